@@ -0,0 +1,38 @@
+//! Compares the merge throughput of different heap arities when merging a large
+//! number of sources. Run with `cargo bench --bench arity`.
+
+use std::{hint::black_box, time::Instant};
+
+use rand::Rng;
+use tournament_kway::Tournament;
+
+const SOURCES: usize = 10_000;
+const ELEMENTS: usize = 100;
+const ROUNDS: usize = 5;
+
+fn bench<const D: usize>(vecs: &[Vec<u64>]) {
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        let t = Tournament::from_iters_min(vecs.iter().map(|v| v.iter())).with_arity::<D>();
+        black_box(t.fold(0, |acc, x| acc ^ x));
+    }
+    println!(
+        "D={D}: {:?} per merge of {SOURCES} sources",
+        start.elapsed() / ROUNDS as u32
+    );
+}
+
+fn main() {
+    let mut rng = rand::thread_rng();
+    let vecs = (0..SOURCES)
+        .map(|_| {
+            let mut v = (0..ELEMENTS).map(|_| rng.gen()).collect::<Vec<u64>>();
+            v.sort();
+            v
+        })
+        .collect::<Vec<_>>();
+
+    bench::<2>(&vecs);
+    bench::<4>(&vecs);
+    bench::<8>(&vecs);
+}
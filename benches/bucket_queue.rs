@@ -0,0 +1,57 @@
+//! Compares a tournament over its built-in heap with a `QueueTournament` over a
+//! `BinaryHeap` and over a `BucketQueue`, on sources of small integers. Run with
+//! `cargo bench --bench bucket_queue`.
+
+use std::{collections::BinaryHeap, hint::black_box, time::Instant};
+
+use rand::Rng;
+use tournament_kway::{BucketQueue, QueueTournament, Tournament};
+
+const SOURCES: usize = 1000;
+const ELEMENTS: usize = 1000;
+const ROUNDS: usize = 10;
+
+fn main() {
+    let mut rng = rand::thread_rng();
+    let vecs = (0..SOURCES)
+        .map(|_| {
+            let mut v = (0..ELEMENTS).map(|_| rng.gen()).collect::<Vec<u8>>();
+            v.sort();
+            v
+        })
+        .collect::<Vec<_>>();
+
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        let t = Tournament::from_iters_min(vecs.iter().map(|v| v.iter().copied()));
+        black_box(t.fold(0u64, |acc, x| acc + x as u64));
+    }
+    println!(
+        "tournament:   {:?} per merge",
+        start.elapsed() / ROUNDS as u32
+    );
+
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        let t =
+            QueueTournament::from_iters(vecs.iter().map(|v| v.iter().copied()), BinaryHeap::new());
+        black_box(t.fold(0u64, |acc, x| acc + x as u64));
+    }
+    println!(
+        "binary heap:  {:?} per merge",
+        start.elapsed() / ROUNDS as u32
+    );
+
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        let t = QueueTournament::from_iters(
+            vecs.iter().map(|v| v.iter().copied()),
+            BucketQueue::default(),
+        );
+        black_box(t.fold(0u64, |acc, x| acc + x as u64));
+    }
+    println!(
+        "bucket queue: {:?} per merge",
+        start.elapsed() / ROUNDS as u32
+    );
+}
@@ -0,0 +1,50 @@
+//! Compares the byte string comparator with the default order of `[u8]`, on
+//! keys that share a long prefix. Run with `cargo bench --bench bytes`.
+
+use std::{hint::black_box, time::Instant};
+
+use rand::Rng;
+use tournament_kway::{BytesComparator, MinComparator, Tournament};
+
+const SOURCES: usize = 64;
+const ELEMENTS: usize = 20_000;
+const PREFIX: usize = 48;
+const ROUNDS: usize = 10;
+
+fn main() {
+    let mut rng = rand::thread_rng();
+    let prefix = (0..PREFIX).map(|_| rng.gen()).collect::<Vec<u8>>();
+    let vecs = (0..SOURCES)
+        .map(|_| {
+            let mut v = (0..ELEMENTS)
+                .map(|_| {
+                    let mut key = prefix.clone();
+                    key.extend(rng.gen::<u64>().to_be_bytes());
+                    key
+                })
+                .collect::<Vec<_>>();
+            v.sort();
+            v
+        })
+        .collect::<Vec<_>>();
+
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        let t = Tournament::from_iters_ref(
+            vecs.iter().map(|v| v.iter().map(Vec::as_slice)),
+            MinComparator::<[u8]>::default(),
+        );
+        black_box(t.fold(0, |acc, key| acc ^ key[PREFIX]));
+    }
+    println!("default: {:?} per merge", start.elapsed() / ROUNDS as u32);
+
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        let t = Tournament::from_iters_ref(
+            vecs.iter().map(|v| v.iter().map(Vec::as_slice)),
+            BytesComparator,
+        );
+        black_box(t.fold(0, |acc, key| acc ^ key[PREFIX]));
+    }
+    println!("bytes:   {:?} per merge", start.elapsed() / ROUNDS as u32);
+}
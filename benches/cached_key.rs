@@ -0,0 +1,65 @@
+//! Compares merging by an expensive key computed on every comparison with
+//! computing it once per element. Run with `cargo bench --bench cached_key`.
+
+use std::{hint::black_box, time::Instant};
+
+use rand::Rng;
+use tournament_kway::{CachedKeyTournament, Tournament};
+
+const SOURCES: usize = 64;
+const ELEMENTS: usize = 20_000;
+const ROUNDS: usize = 5;
+
+/// Parse a `YYYY-MM-DD` date into a sortable number
+fn date_key(date: &str) -> u32 {
+    let mut parts = date.split('-').map(|p| p.parse::<u32>().unwrap());
+    let (y, m, d) = (
+        parts.next().unwrap(),
+        parts.next().unwrap(),
+        parts.next().unwrap(),
+    );
+    y * 10000 + m * 100 + d
+}
+
+fn main() {
+    let mut rng = rand::thread_rng();
+    let vecs = (0..SOURCES)
+        .map(|_| {
+            let mut v = (0..ELEMENTS)
+                .map(|_| {
+                    format!(
+                        "{}-{}-{}",
+                        rng.gen_range(1970..2030),
+                        rng.gen_range(1..13),
+                        rng.gen_range(1..29)
+                    )
+                })
+                .collect::<Vec<_>>();
+            v.sort_by_key(|s| date_key(s));
+            v
+        })
+        .collect::<Vec<_>>();
+
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        let t =
+            Tournament::from_iters_by_key(vecs.iter().map(|v| v.iter()), |s: &&String| date_key(s));
+        black_box(t.fold(0, |acc, s| acc ^ s.len()));
+    }
+    println!(
+        "by key:     {:?} per merge",
+        start.elapsed() / ROUNDS as u32
+    );
+
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        let t = CachedKeyTournament::from_iters(vecs.iter().map(|v| v.iter()), |s: &&String| {
+            date_key(s)
+        });
+        black_box(t.fold(0, |acc, s| acc ^ s.len()));
+    }
+    println!(
+        "cached key: {:?} per merge",
+        start.elapsed() / ROUNDS as u32
+    );
+}
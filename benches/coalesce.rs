@@ -0,0 +1,57 @@
+//! Compares a plain merge with one coalesced into runs from the winning source, by
+//! time and by the number of comparisons made. The skewed source sizes from the
+//! README interleave element by element, so they show the overhead, while sources
+//! that take turns in blocks show the gain. Run with `cargo bench --bench coalesce`.
+
+use std::{hint::black_box, time::Instant};
+
+use tournament_kway::{CountingComparator, MinComparator, Tournament};
+
+const ROUNDS: usize = 30;
+
+fn bench(name: &str, sources: &[Vec<u64>]) {
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        let t = Tournament::from_iters_min(sources.iter().map(|s| s.iter()));
+        black_box(t.fold(0, |acc, x| acc ^ x));
+    }
+    let elapsed = start.elapsed() / ROUNDS as u32;
+    let comparator = CountingComparator::new(MinComparator::default());
+    let t = Tournament::from_iters(sources.iter().map(|s| s.iter()), comparator.clone());
+    black_box(t.fold(0, |acc, x| acc ^ x));
+    println!(
+        "{name} heap:          {elapsed:?} per merge, {} comparisons",
+        comparator.count()
+    );
+
+    for max_run in [16, 256] {
+        let start = Instant::now();
+        for _ in 0..ROUNDS {
+            let t = Tournament::from_iters_min(sources.iter().map(|s| s.iter()));
+            black_box(t.coalesce(max_run).fold(0, |acc, x| acc ^ x));
+        }
+        let elapsed = start.elapsed() / ROUNDS as u32;
+        let comparator = CountingComparator::new(MinComparator::default());
+        let t = Tournament::from_iters(sources.iter().map(|s| s.iter()), comparator.clone());
+        black_box(t.coalesce(max_run).fold(0, |acc, x| acc ^ x));
+        println!(
+            "{name} coalesce({max_run:>3}): {elapsed:?} per merge, {} comparisons",
+            comparator.count()
+        );
+    }
+}
+
+fn main() {
+    let skewed = [
+        (1..2000).collect::<Vec<u64>>(),
+        (1..20000).collect(),
+        (1..5000000).collect(),
+    ];
+    bench("skewed", &skewed);
+
+    // Every source holds every third block of a thousand consecutive numbers
+    let blocks = (0..3u64)
+        .map(|s| (0..3000000).filter(|x| (x / 1000) % 3 == s).collect())
+        .collect::<Vec<_>>();
+    bench("blocks", &blocks);
+}
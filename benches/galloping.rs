@@ -0,0 +1,33 @@
+//! Compares the galloping merge of sorted slices with the general heap based merge,
+//! on the skewed source sizes from the README. Run with `cargo bench --bench galloping`.
+
+use std::{hint::black_box, time::Instant};
+
+use tournament_kway::{MinComparator, Tournament};
+
+const ROUNDS: usize = 10;
+
+fn main() {
+    let sources = [
+        (1..2000).collect::<Vec<u64>>(),
+        (1..20000).collect(),
+        (1..5000000).collect(),
+    ];
+
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        let t = Tournament::from_iters_min(sources.iter().map(|s| s.iter()));
+        black_box(t.fold(0, |acc, x| acc ^ x));
+    }
+    println!("heap:      {:?} per merge", start.elapsed() / ROUNDS as u32);
+
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        let t = Tournament::from_sorted_slices(
+            sources.iter().map(Vec::as_slice),
+            MinComparator::default(),
+        );
+        black_box(t.fold(0, |acc, x| acc ^ x));
+    }
+    println!("galloping: {:?} per merge", start.elapsed() / ROUNDS as u32);
+}
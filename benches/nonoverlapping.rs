@@ -0,0 +1,37 @@
+//! Compares the general heap merge with chaining sources that do not overlap, as
+//! with the shards of a range partitioned table. Run with
+//! `cargo bench --bench nonoverlapping`.
+
+use std::{hint::black_box, time::Instant};
+
+use tournament_kway::{MinComparator, Tournament};
+
+const ROUNDS: usize = 30;
+
+fn main() {
+    for shards in [4u64, 64, 1024] {
+        let len = 4_000_000 / shards;
+        let sources = (0..shards)
+            .map(|s| (s * len..(s + 1) * len).collect::<Vec<u64>>())
+            .collect::<Vec<_>>();
+
+        let start = Instant::now();
+        for _ in 0..ROUNDS {
+            let t = Tournament::from_iters_min(sources.iter().map(|s| s.iter()));
+            black_box(t.fold(0, |acc, x| acc ^ x));
+        }
+        let heap = start.elapsed() / ROUNDS as u32;
+
+        let start = Instant::now();
+        for _ in 0..ROUNDS {
+            let t = Tournament::from_sorted_nonoverlapping(
+                sources.iter().map(|s| s.iter()),
+                MinComparator::default(),
+            );
+            black_box(t.fold(0, |acc, x| acc ^ x));
+        }
+        let chained = start.elapsed() / ROUNDS as u32;
+
+        println!("{shards:>4} shards: heap {heap:?}, chained {chained:?} per merge");
+    }
+}
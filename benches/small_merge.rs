@@ -0,0 +1,21 @@
+//! Builds and drains many small merges of 4 sources in a tight loop, where
+//! allocating the heap and the sources is a large part of the cost. Compare
+//! `cargo bench --bench small_merge` with
+//! `cargo bench --bench small_merge --features smallvec`.
+
+use std::{hint::black_box, time::Instant};
+
+use tournament_kway::Tournament;
+
+const MERGES: usize = 1_000_000;
+
+fn main() {
+    let sources = [[1, 5, 9], [2, 6, 10], [3, 7, 11], [4, 8, 12]];
+
+    let start = Instant::now();
+    for _ in 0..MERGES {
+        let t = Tournament::from_iters_min(black_box(sources).map(IntoIterator::into_iter));
+        black_box(t.sum::<u64>());
+    }
+    println!("k=4: {:?} per merge", start.elapsed() / MERGES as u32);
+}
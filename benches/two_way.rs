@@ -0,0 +1,33 @@
+//! Compares the two-way merge with the general heap based merge of two sources.
+//! Run with `cargo bench --bench two_way`.
+
+use std::{hint::black_box, time::Instant};
+
+use rand::Rng;
+use tournament_kway::{MinComparator, Tournament};
+
+const ELEMENTS: usize = 1_000_000;
+const ROUNDS: usize = 10;
+
+fn main() {
+    let mut rng = rand::thread_rng();
+    let [a, b] = [0, 1].map(|_| {
+        let mut v = (0..ELEMENTS).map(|_| rng.gen()).collect::<Vec<u64>>();
+        v.sort();
+        v
+    });
+
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        let t = Tournament::from_iters_min([a.iter(), b.iter()]);
+        black_box(t.fold(0, |acc, x| acc ^ x));
+    }
+    println!("heap:    {:?} per merge", start.elapsed() / ROUNDS as u32);
+
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        let t = Tournament::merge2(a.iter(), b.iter(), MinComparator::default());
+        black_box(t.fold(0, |acc, x| acc ^ x));
+    }
+    println!("two way: {:?} per merge", start.elapsed() / ROUNDS as u32);
+}
@@ -0,0 +1,1598 @@
+use std::collections::VecDeque;
+
+use streaming_iterator::{StreamingIterator, StreamingIteratorMut};
+
+use crate::{comparator::Comparator, error::UnsortedError, Tournament};
+
+/// An iterator over the merged output of a [`Tournament`] in batches.
+///
+/// Created by [`Tournament::chunks`].
+pub struct Chunks<T, C, const D: usize = 2>
+where
+    T: Iterator,
+{
+    tournament: Tournament<T, C, D>,
+    size: usize,
+}
+
+impl<T, C, const D: usize> Chunks<T, C, D>
+where
+    T: Iterator,
+{
+    pub(crate) fn new(tournament: Tournament<T, C, D>, size: usize) -> Self {
+        assert!(size != 0, "chunk size must be non-zero");
+        Chunks { tournament, size }
+    }
+}
+
+impl<T, C, const D: usize> Iterator for Chunks<T, C, D>
+where
+    T: Iterator,
+    C: Comparator<T::Item>,
+{
+    type Item = Vec<T::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let chunk = self.tournament.by_ref().take(self.size).collect::<Vec<_>>();
+        (!chunk.is_empty()).then_some(chunk)
+    }
+}
+
+/// An iterator over every `n`th element of the merged output of a [`Tournament`],
+/// starting with the first.
+///
+/// Created by [`Tournament::step_by`].
+pub struct StepBy<T, C, const D: usize = 2>
+where
+    T: Iterator,
+{
+    tournament: Tournament<T, C, D>,
+    step: usize,
+    first: bool,
+}
+
+impl<T, C, const D: usize> StepBy<T, C, D>
+where
+    T: Iterator,
+{
+    pub(crate) fn new(tournament: Tournament<T, C, D>, step: usize) -> Self {
+        assert!(step != 0, "step must be non-zero");
+        StepBy {
+            tournament,
+            step,
+            first: true,
+        }
+    }
+
+    /// The number of sources that still have elements left to merge.
+    pub fn active_sources(&self) -> usize {
+        self.tournament.active_sources()
+    }
+}
+
+impl<T, C, const D: usize> Iterator for StepBy<T, C, D>
+where
+    T: Iterator,
+    C: Comparator<T::Item>,
+{
+    type Item = T::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if std::mem::take(&mut self.first) {
+            self.tournament.next()
+        } else {
+            self.tournament.nth(self.step - 1)
+        }
+    }
+}
+
+/// An iterator over the merged output of a [`Tournament`] that keeps at most a
+/// number of elements out of every run of equal ones.
+///
+/// Created by [`Tournament::dedup_keep`].
+pub struct DedupKeep<T, C, const D: usize = 2>
+where
+    T: Iterator,
+{
+    tournament: Tournament<T, C, D>,
+    keep: usize,
+    // The number of elements yielded from the current run
+    run: usize,
+}
+
+impl<T, C, const D: usize> DedupKeep<T, C, D>
+where
+    T: Iterator,
+{
+    pub(crate) fn new(tournament: Tournament<T, C, D>, keep: usize) -> Self {
+        assert!(keep != 0, "the number of elements to keep must be non-zero");
+        DedupKeep {
+            tournament,
+            keep,
+            run: 0,
+        }
+    }
+
+    /// The number of sources that still have elements left to merge.
+    pub fn active_sources(&self) -> usize {
+        self.tournament.active_sources()
+    }
+}
+
+impl<T, C, const D: usize> Iterator for DedupKeep<T, C, D>
+where
+    T: Iterator,
+    C: Comparator<T::Item>,
+{
+    type Item = T::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.tournament.next()?;
+        self.run += 1;
+
+        let same_run = |tournament: &Tournament<T, C, D>| {
+            tournament
+                .peek()
+                .is_some_and(|head| tournament.comparator().cmp(head, &item).is_eq())
+        };
+        if self.run == self.keep {
+            while same_run(&self.tournament) {
+                self.tournament.next();
+            }
+            self.run = 0;
+        } else if !same_run(&self.tournament) {
+            self.run = 0;
+        }
+
+        Some(item)
+    }
+}
+
+/// An iterator that applies a monotonic function to the merged output of a
+/// [`Tournament`], so the output stays sorted.
+///
+/// Created by [`Tournament::map_monotonic`].
+pub struct MapMonotonic<T, C, F, const D: usize = 2>
+where
+    T: Iterator,
+{
+    tournament: Tournament<T, C, D>,
+    f: F,
+}
+
+impl<T, C, F, const D: usize> MapMonotonic<T, C, F, D>
+where
+    T: Iterator,
+{
+    pub(crate) fn new(tournament: Tournament<T, C, D>, f: F) -> Self {
+        MapMonotonic { tournament, f }
+    }
+
+    /// The number of sources that still have elements left to merge.
+    pub fn active_sources(&self) -> usize {
+        self.tournament.active_sources()
+    }
+
+    /// Yield every distinct mapped element exactly once, as decided by `comparator`
+    /// returning [`Ordering::Equal`](std::cmp::Ordering::Equal). The comparator
+    /// must order the mapped elements the same way the tournament orders the
+    /// original ones.
+    pub fn union<U, CU>(self, comparator: CU) -> MappedUnion<Self, CU>
+    where
+        F: FnMut(T::Item) -> U,
+        C: Comparator<T::Item>,
+        CU: Comparator<U>,
+    {
+        MappedUnion {
+            iter: self,
+            next: None,
+            comparator,
+        }
+    }
+}
+
+impl<T, C, F, U, const D: usize> Iterator for MapMonotonic<T, C, F, D>
+where
+    T: Iterator,
+    C: Comparator<T::Item>,
+    F: FnMut(T::Item) -> U,
+{
+    type Item = U;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.tournament.next().map(&mut self.f)
+    }
+}
+
+/// An iterator over the distinct elements of a [`MapMonotonic`].
+///
+/// Created by [`MapMonotonic::union`].
+pub struct MappedUnion<I, C>
+where
+    I: Iterator,
+{
+    iter: I,
+    // The first element of the next run, pulled while skipping the previous one
+    next: Option<I::Item>,
+    comparator: C,
+}
+
+impl<I, C> Iterator for MappedUnion<I, C>
+where
+    I: Iterator,
+    C: Comparator<I::Item>,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.next.take().or_else(|| self.iter.next())?;
+
+        for next in self.iter.by_ref() {
+            if self.comparator.cmp(&next, &item).is_ne() {
+                self.next = Some(next);
+                break;
+            }
+        }
+
+        Some(item)
+    }
+}
+
+/// An iterator over the merged elements of a [`Tournament`] that satisfy a predicate.
+///
+/// Created by [`Tournament::filter`].
+pub struct Filter<T, C, P, const D: usize = 2>
+where
+    T: Iterator,
+{
+    tournament: Tournament<T, C, D>,
+    predicate: P,
+}
+
+impl<T, C, P, const D: usize> Filter<T, C, P, D>
+where
+    T: Iterator,
+    C: Comparator<T::Item>,
+    P: FnMut(&T::Item) -> bool,
+{
+    pub(crate) fn new(tournament: Tournament<T, C, D>, predicate: P) -> Self {
+        Filter {
+            tournament,
+            predicate,
+        }
+    }
+
+    /// The next accepted element, without yielding it. Rejected elements ahead of
+    /// it are skipped.
+    pub fn peek(&mut self) -> Option<&T::Item> {
+        while let Some(head) = self.tournament.peek() {
+            if (self.predicate)(head) {
+                break;
+            }
+            self.tournament.next();
+        }
+        self.tournament.peek()
+    }
+
+    /// The number of sources that still have elements left to merge, whether or
+    /// not any of them will be accepted.
+    pub fn active_sources(&self) -> usize {
+        self.tournament.active_sources()
+    }
+}
+
+impl<T, C, P, const D: usize> Iterator for Filter<T, C, P, D>
+where
+    T: Iterator,
+    C: Comparator<T::Item>,
+    P: FnMut(&T::Item) -> bool,
+{
+    type Item = T::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.peek()?;
+        self.tournament.next()
+    }
+}
+
+/// An iterator that both filters and maps the merged elements of a [`Tournament`].
+///
+/// Created by [`Tournament::filter_map`].
+pub struct FilterMap<T, C, F, U, const D: usize = 2>
+where
+    T: Iterator,
+{
+    tournament: Tournament<T, C, D>,
+    f: F,
+    // The next accepted element, pulled by `peek`
+    next: Option<U>,
+}
+
+impl<T, C, F, U, const D: usize> FilterMap<T, C, F, U, D>
+where
+    T: Iterator,
+    C: Comparator<T::Item>,
+    F: FnMut(T::Item) -> Option<U>,
+{
+    pub(crate) fn new(tournament: Tournament<T, C, D>, f: F) -> Self {
+        FilterMap {
+            tournament,
+            f,
+            next: None,
+        }
+    }
+
+    /// The next accepted element, without yielding it. Since only accepted elements
+    /// are mapped, it has to be pulled out of the tournament and is kept until the
+    /// next call to `next`.
+    pub fn peek(&mut self) -> Option<&U> {
+        if self.next.is_none() {
+            self.next = self.tournament.by_ref().find_map(&mut self.f);
+        }
+        self.next.as_ref()
+    }
+
+    /// The number of sources that still have elements left to merge, not counting
+    /// an element held by [`FilterMap::peek`].
+    pub fn active_sources(&self) -> usize {
+        self.tournament.active_sources()
+    }
+}
+
+impl<T, C, F, U, const D: usize> Iterator for FilterMap<T, C, F, U, D>
+where
+    T: Iterator,
+    C: Comparator<T::Item>,
+    F: FnMut(T::Item) -> Option<U>,
+{
+    type Item = U;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next
+            .take()
+            .or_else(|| self.tournament.by_ref().find_map(&mut self.f))
+    }
+}
+
+/// An iterator over groups of consecutive merged elements of a [`Tournament`]
+/// that share a key.
+///
+/// Created by [`Tournament::group_by_key`].
+pub struct GroupByKey<T, C, F, const D: usize = 2>
+where
+    T: Iterator,
+{
+    tournament: Tournament<T, C, D>,
+    key_fn: F,
+}
+
+impl<T, C, F, const D: usize> GroupByKey<T, C, F, D>
+where
+    T: Iterator,
+{
+    pub(crate) fn new(tournament: Tournament<T, C, D>, key_fn: F) -> Self {
+        GroupByKey { tournament, key_fn }
+    }
+}
+
+impl<T, C, F, K, const D: usize> Iterator for GroupByKey<T, C, F, D>
+where
+    T: Iterator,
+    C: Comparator<T::Item>,
+    F: FnMut(&T::Item) -> K,
+    K: PartialEq,
+{
+    type Item = (K, Vec<T::Item>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let first = self.tournament.next()?;
+        let key = (self.key_fn)(&first);
+        let mut group = vec![first];
+
+        while let Some(head) = self.tournament.peek() {
+            if (self.key_fn)(head) != key {
+                break;
+            }
+            group.extend(self.tournament.next());
+        }
+
+        Some((key, group))
+    }
+}
+
+/// A [`Tournament`] that can look at its next merged elements, by pulling them
+/// ahead.
+///
+/// Created by [`Tournament::peekable`].
+pub struct PeekableTournament<T, C, const D: usize = 2>
+where
+    T: Iterator,
+{
+    tournament: Tournament<T, C, D>,
+    // The elements pulled ahead, in merged order
+    peeked: VecDeque<T::Item>,
+}
+
+impl<T, C, const D: usize> PeekableTournament<T, C, D>
+where
+    T: Iterator,
+    C: Comparator<T::Item>,
+{
+    pub(crate) fn new(tournament: Tournament<T, C, D>) -> Self {
+        PeekableTournament {
+            tournament,
+            peeked: VecDeque::new(),
+        }
+    }
+
+    /// The next merged element, without yielding it.
+    pub fn peek(&mut self) -> Option<&T::Item> {
+        self.fill(1);
+        self.peeked.front()
+    }
+
+    /// Mutable access to the next merged element, without yielding it. Modifying it
+    /// does not affect the order of the remaining elements.
+    pub fn peek_mut(&mut self) -> Option<&mut T::Item> {
+        self.fill(1);
+        self.peeked.front_mut()
+    }
+
+    /// The next `n` merged elements without yielding them, or fewer if the sources
+    /// run out, for example to decide on the size of a batch. The elements are
+    /// pulled ahead and kept, so later calls to `next` still yield them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tournament_kway::Tournament;
+    ///
+    /// let mut t = Tournament::from_iters_min([(1..3), (2..4)]).peekable();
+    /// assert_eq!(t.peek_many(3), [1, 2, 2]);
+    /// assert_eq!(t.next(), Some(1));
+    /// assert_eq!(t.peek_many(5), [2, 2, 3]);
+    /// ```
+    pub fn peek_many(&mut self, n: usize) -> &[T::Item] {
+        self.fill(n);
+        let peeked = self.peeked.make_contiguous();
+        &peeked[..n.min(peeked.len())]
+    }
+
+    /// The number of sources that still have elements left to merge, not counting
+    /// the elements held by [`PeekableTournament::peek`].
+    pub fn active_sources(&self) -> usize {
+        self.tournament.active_sources()
+    }
+
+    // Pull elements ahead until `n` are held, or the tournament is exhausted
+    fn fill(&mut self, n: usize) {
+        while self.peeked.len() < n {
+            match self.tournament.next() {
+                Some(item) => self.peeked.push_back(item),
+                None => break,
+            }
+        }
+    }
+}
+
+impl<T, C, const D: usize> Iterator for PeekableTournament<T, C, D>
+where
+    T: Iterator,
+    C: Comparator<T::Item>,
+{
+    type Item = T::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.peeked.pop_front() {
+            Some(peeked) => Some(peeked),
+            None => self.tournament.next(),
+        }
+    }
+}
+
+/// An iterator that calls a function with every merged element of a
+/// [`Tournament`] and the index of its source.
+///
+/// Created by [`Tournament::inspect_source`].
+pub struct InspectSource<T, C, F, const D: usize = 2>
+where
+    T: Iterator,
+{
+    tournament: Tournament<T, C, D>,
+    f: F,
+}
+
+impl<T, C, F, const D: usize> InspectSource<T, C, F, D>
+where
+    T: Iterator,
+{
+    pub(crate) fn new(tournament: Tournament<T, C, D>, f: F) -> Self {
+        InspectSource { tournament, f }
+    }
+
+    /// The number of sources that still have elements left to merge.
+    pub fn active_sources(&self) -> usize {
+        self.tournament.active_sources()
+    }
+}
+
+impl<T, C, F, const D: usize> Iterator for InspectSource<T, C, F, D>
+where
+    T: Iterator,
+    C: Comparator<T::Item>,
+    F: FnMut(usize, &T::Item),
+{
+    type Item = T::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (slot, item) = self.tournament.next_with_index()?;
+        (self.f)(self.tournament.source_id(slot), &item);
+        Some(item)
+    }
+}
+
+/// Running statistics over the merged output of a [`Tournament`], as kept by
+/// [`WithStats`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MergeStats {
+    /// The number of elements yielded so far
+    pub emitted: usize,
+    /// The number of elements yielded from every source, by the index of the source
+    /// among all the sources added, including empty ones
+    pub per_source_counts: Vec<usize>,
+    /// The largest number of entries the heap held before yielding an element
+    pub max_heap_len: usize,
+}
+
+/// An iterator over the merged elements of a [`Tournament`] that keeps running
+/// [`MergeStats`] over them.
+///
+/// Created by [`Tournament::with_stats`].
+pub struct WithStats<T, C, const D: usize = 2>
+where
+    T: Iterator,
+{
+    tournament: Tournament<T, C, D>,
+    stats: MergeStats,
+}
+
+impl<T, C, const D: usize> WithStats<T, C, D>
+where
+    T: Iterator,
+{
+    pub(crate) fn new(tournament: Tournament<T, C, D>) -> Self {
+        let stats = MergeStats {
+            emitted: 0,
+            per_source_counts: vec![0; tournament.sources_len()],
+            max_heap_len: tournament.heap_len(),
+        };
+        WithStats { tournament, stats }
+    }
+
+    /// The statistics over the elements yielded so far.
+    pub fn stats(&self) -> &MergeStats {
+        &self.stats
+    }
+
+    /// The number of sources that still have elements left to merge.
+    pub fn active_sources(&self) -> usize {
+        self.tournament.active_sources()
+    }
+
+    /// Take back the tournament and the final statistics.
+    pub fn into_inner(self) -> (Tournament<T, C, D>, MergeStats) {
+        (self.tournament, self.stats)
+    }
+}
+
+impl<T, C, const D: usize> Iterator for WithStats<T, C, D>
+where
+    T: Iterator,
+    C: Comparator<T::Item>,
+{
+    type Item = T::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let heap_len = self.tournament.heap_len();
+        let (slot, item) = self.tournament.next_with_index()?;
+        self.stats.emitted += 1;
+        self.stats.per_source_counts[self.tournament.source_id(slot)] += 1;
+        self.stats.max_heap_len = self.stats.max_heap_len.max(heap_len);
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.tournament.size_hint()
+    }
+}
+
+/// A [`StreamingIterator`] that lends the merged elements of a [`Tournament`]
+/// instead of moving them out.
+///
+/// Created by [`Tournament::lending`].
+pub struct Lending<T, C, const D: usize = 2>
+where
+    T: Iterator,
+{
+    tournament: Tournament<T, C, D>,
+    current: Option<T::Item>,
+}
+
+impl<T, C, const D: usize> Lending<T, C, D>
+where
+    T: Iterator,
+{
+    pub(crate) fn new(tournament: Tournament<T, C, D>) -> Self {
+        Lending {
+            tournament,
+            current: None,
+        }
+    }
+
+    /// The number of sources that still have elements left to merge.
+    pub fn active_sources(&self) -> usize {
+        self.tournament.active_sources()
+    }
+
+    /// Take back the tournament, dropping the current element.
+    pub fn into_inner(self) -> Tournament<T, C, D> {
+        self.tournament
+    }
+}
+
+impl<T, C, const D: usize> StreamingIterator for Lending<T, C, D>
+where
+    T: Iterator,
+    C: Comparator<T::Item>,
+{
+    type Item = T::Item;
+
+    fn advance(&mut self) {
+        self.current = self.tournament.next();
+    }
+
+    fn get(&self) -> Option<&Self::Item> {
+        self.current.as_ref()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.tournament.size_hint()
+    }
+}
+
+impl<T, C, const D: usize> StreamingIteratorMut for Lending<T, C, D>
+where
+    T: Iterator,
+    C: Comparator<T::Item>,
+{
+    fn get_mut(&mut self) -> Option<&mut Self::Item> {
+        self.current.as_mut()
+    }
+}
+
+/// An iterator over the merged elements of a [`Tournament`] that keeps yielding
+/// from the winning source for as long as it beats the runner up.
+///
+/// Created by [`Tournament::coalesce`].
+pub struct Coalesce<T, C, const D: usize = 2>
+where
+    T: Iterator,
+{
+    tournament: Tournament<T, C, D>,
+    max_run: usize,
+    // The elements left in the current run, and the position of its runner up
+    run: Option<(usize, usize)>,
+}
+
+impl<T, C, const D: usize> Coalesce<T, C, D>
+where
+    T: Iterator,
+{
+    pub(crate) fn new(tournament: Tournament<T, C, D>, max_run: usize) -> Self {
+        assert!(max_run != 0, "max run must be non-zero");
+        Coalesce {
+            tournament,
+            max_run,
+            run: None,
+        }
+    }
+
+    /// The number of sources that still have elements left to merge.
+    pub fn active_sources(&self) -> usize {
+        self.tournament.active_sources()
+    }
+}
+
+impl<T, C, const D: usize> Iterator for Coalesce<T, C, D>
+where
+    T: Iterator,
+    C: Comparator<T::Item>,
+{
+    type Item = T::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (left, runner_up) = match self.run.take() {
+            Some(run) => run,
+            None => match self.tournament.runner_up() {
+                Some(runner_up) => (self.max_run, runner_up),
+                None => return self.tournament.next(),
+            },
+        };
+
+        let (item, kept) = self.tournament.next_in_run(runner_up)?;
+        if kept && left > 1 {
+            self.run = Some((left - 1, runner_up));
+        }
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.tournament.size_hint()
+    }
+}
+
+/// An iterator over the merged elements of a [`Tournament`] and their ranks.
+///
+/// Created by [`Tournament::ranked`].
+pub struct Ranked<T, C, const D: usize = 2>
+where
+    T: Iterator,
+{
+    tournament: Tournament<T, C, D>,
+    rank: usize,
+}
+
+impl<T, C, const D: usize> Ranked<T, C, D>
+where
+    T: Iterator,
+{
+    pub(crate) fn new(tournament: Tournament<T, C, D>) -> Self {
+        Ranked {
+            tournament,
+            rank: 0,
+        }
+    }
+
+    /// The rank the next merged element will be yielded with.
+    pub fn next_rank(&self) -> usize {
+        self.rank
+    }
+
+    /// The number of sources that still have elements left to merge.
+    pub fn active_sources(&self) -> usize {
+        self.tournament.active_sources()
+    }
+}
+
+impl<T, C, const D: usize> Iterator for Ranked<T, C, D>
+where
+    T: Iterator,
+    C: Comparator<T::Item>,
+{
+    type Item = (usize, T::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.tournament.next()?;
+        let rank = self.rank;
+        self.rank += 1;
+        Some((rank, item))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.tournament.size_hint()
+    }
+}
+
+/// An iterator over the maximal runs of merged elements of a [`Tournament`] that
+/// come from the same source.
+///
+/// Created by [`Tournament::runs`].
+pub struct Runs<T, C, const D: usize = 2>
+where
+    T: Iterator,
+{
+    tournament: Tournament<T, C, D>,
+    // The first element of the next run and the index of its source, pulled ahead
+    // to end the previous run
+    next_run: Option<(usize, T::Item)>,
+}
+
+impl<T, C, const D: usize> Runs<T, C, D>
+where
+    T: Iterator,
+{
+    pub(crate) fn new(tournament: Tournament<T, C, D>) -> Self {
+        Runs {
+            tournament,
+            next_run: None,
+        }
+    }
+
+    /// The number of sources that still have elements left to merge.
+    pub fn active_sources(&self) -> usize {
+        self.tournament.active_sources()
+    }
+}
+
+impl<T, C, const D: usize> Runs<T, C, D>
+where
+    T: Iterator,
+    C: Comparator<T::Item>,
+{
+    fn next_with_source(&mut self) -> Option<(usize, T::Item)> {
+        let (slot, item) = self.tournament.next_with_index()?;
+        Some((self.tournament.source_id(slot), item))
+    }
+}
+
+impl<T, C, const D: usize> Iterator for Runs<T, C, D>
+where
+    T: Iterator,
+    C: Comparator<T::Item>,
+{
+    type Item = (usize, Vec<T::Item>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (source, first) = match self.next_run.take() {
+            Some(next_run) => next_run,
+            None => self.next_with_source()?,
+        };
+        let mut run = vec![first];
+
+        while let Some((next_source, item)) = self.next_with_source() {
+            if next_source != source {
+                self.next_run = Some((next_source, item));
+                break;
+            }
+            run.push(item);
+        }
+
+        Some((source, run))
+    }
+}
+
+/// An iterator over the merged elements of a [`Tournament`] that checks that
+/// every source is sorted while merging.
+///
+/// Created by [`Tournament::checked`].
+pub struct Checked<T, C, const D: usize = 2>
+where
+    T: Iterator,
+{
+    tournament: Tournament<T, C, D>,
+    // The number of elements every slot yielded so far
+    yielded: Vec<usize>,
+    // The violation found while yielding the previous element, which is yielded
+    // next, after which the iterator is done
+    error: Option<Option<UnsortedError>>,
+}
+
+impl<T, C, const D: usize> Checked<T, C, D>
+where
+    T: Iterator,
+{
+    pub(crate) fn new(tournament: Tournament<T, C, D>) -> Self {
+        Checked {
+            yielded: vec![0; tournament.slots_len()],
+            tournament,
+            error: None,
+        }
+    }
+
+    /// The number of sources that still have elements left to merge.
+    pub fn active_sources(&self) -> usize {
+        self.tournament.active_sources()
+    }
+}
+
+impl<T, C, const D: usize> Iterator for Checked<T, C, D>
+where
+    T: Iterator,
+    C: Comparator<T::Item>,
+{
+    type Item = Result<T::Item, UnsortedError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(error) = &mut self.error {
+            return error.take().map(Err);
+        }
+
+        let (slot, item, sorted) = self.tournament.next_checked()?;
+        self.yielded[slot] += 1;
+        if !sorted {
+            let source = self.tournament.source_id(slot);
+            self.error = Some(Some(UnsortedError::new(source, self.yielded[slot])));
+        }
+        Some(Ok(item))
+    }
+}
+
+/// An iterator over the merged elements of a borrowed [`Tournament`] for as long as
+/// they satisfy a predicate.
+///
+/// Created by [`Tournament::take_while_key`].
+pub struct TakeWhileKey<'a, T, C, F, const D: usize = 2>
+where
+    T: Iterator,
+{
+    tournament: &'a mut Tournament<T, C, D>,
+    pred: F,
+    // Set once an element was rejected, so it is not tested again
+    done: bool,
+}
+
+impl<'a, T, C, F, const D: usize> TakeWhileKey<'a, T, C, F, D>
+where
+    T: Iterator,
+{
+    pub(crate) fn new(tournament: &'a mut Tournament<T, C, D>, pred: F) -> Self {
+        TakeWhileKey {
+            tournament,
+            pred,
+            done: false,
+        }
+    }
+
+    /// The number of sources that still have elements left to merge.
+    pub fn active_sources(&self) -> usize {
+        self.tournament.active_sources()
+    }
+}
+
+impl<T, C, F, const D: usize> Iterator for TakeWhileKey<'_, T, C, F, D>
+where
+    T: Iterator,
+    C: Comparator<T::Item>,
+    F: FnMut(&T::Item) -> bool,
+{
+    type Item = T::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        if !(self.pred)(self.tournament.peek()?) {
+            self.done = true;
+            return None;
+        }
+        self.tournament.next()
+    }
+}
+
+/// A [`Tournament`] that is only built from its sources once the merge starts.
+///
+/// Created by [`Tournament::from_iters_lazy`].
+#[must_use = "a lazy tournament does not touch its sources until it is iterated"]
+pub struct LazyTournament<I, C>
+where
+    I: Iterator,
+    I::Item: Iterator,
+{
+    // The sources and the comparator, until the tournament is built
+    pending: Option<(I, C)>,
+    tournament: Option<Tournament<I::Item, C>>,
+}
+
+impl<I, C> LazyTournament<I, C>
+where
+    I: Iterator,
+    I::Item: Iterator,
+    C: Comparator<<I::Item as Iterator>::Item>,
+{
+    pub(crate) fn new(iters: I, comparator: C) -> Self {
+        LazyTournament {
+            pending: Some((iters, comparator)),
+            tournament: None,
+        }
+    }
+
+    /// Returns `true` once the tournament was built, pulling the first element of
+    /// every source.
+    pub fn is_started(&self) -> bool {
+        self.tournament.is_some()
+    }
+
+    /// The tournament, building it first if the merge did not start yet.
+    pub fn tournament(&mut self) -> &mut Tournament<I::Item, C> {
+        let pending = &mut self.pending;
+        self.tournament.get_or_insert_with(|| {
+            let (iters, comparator) = pending.take().expect("an unbuilt tournament is pending");
+            Tournament::from_iters(iters, comparator)
+        })
+    }
+
+    /// Take the tournament, building it first if the merge did not start yet.
+    pub fn into_inner(mut self) -> Tournament<I::Item, C> {
+        self.tournament();
+        self.tournament.expect("the tournament was just built")
+    }
+}
+
+impl<I, C> Iterator for LazyTournament<I, C>
+where
+    I: Iterator,
+    I::Item: Iterator,
+    C: Comparator<<I::Item as Iterator>::Item>,
+{
+    type Item = <I::Item as Iterator>::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.tournament().next()
+    }
+}
+
+/// A [`Tournament`] that yields at most a number of merged elements.
+///
+/// Created by [`Tournament::limit`].
+pub struct LimitedTournament<T, C, const D: usize = 2>
+where
+    T: Iterator,
+{
+    tournament: Tournament<T, C, D>,
+    budget: usize,
+}
+
+impl<T, C, const D: usize> LimitedTournament<T, C, D>
+where
+    T: Iterator,
+{
+    pub(crate) fn new(tournament: Tournament<T, C, D>, budget: usize) -> Self {
+        LimitedTournament { tournament, budget }
+    }
+
+    /// The next merged element, without yielding it, or `None` once the limit is
+    /// reached.
+    pub fn peek(&self) -> Option<&T::Item> {
+        if self.budget == 0 {
+            return None;
+        }
+        self.tournament.peek()
+    }
+
+    /// The number of elements left to yield before the limit is reached, which
+    /// can be more than the elements the sources have left.
+    pub fn remaining_budget(&self) -> usize {
+        self.budget
+    }
+
+    /// The number of sources that still have elements left to merge.
+    pub fn active_sources(&self) -> usize {
+        self.tournament.active_sources()
+    }
+
+    /// Take back the tournament, to continue the merge past the limit.
+    pub fn into_inner(self) -> Tournament<T, C, D> {
+        self.tournament
+    }
+}
+
+impl<T, C, const D: usize> Iterator for LimitedTournament<T, C, D>
+where
+    T: Iterator,
+    C: Comparator<T::Item>,
+{
+    type Item = T::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.budget == 0 {
+            return None;
+        }
+        let item = self.tournament.next()?;
+        self.budget -= 1;
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.tournament.size_hint();
+        (
+            lower.min(self.budget),
+            Some(upper.map_or(self.budget, |upper| upper.min(self.budget))),
+        )
+    }
+}
+
+/// A [`Tournament`] that yields merged elements until a deadline passes.
+///
+/// Created by [`Tournament::take_until`].
+#[cfg(feature = "std")]
+pub struct TakeUntil<T, C, const D: usize = 2>
+where
+    T: Iterator,
+{
+    tournament: Tournament<T, C, D>,
+    deadline: std::time::Instant,
+}
+
+#[cfg(feature = "std")]
+impl<T, C, const D: usize> TakeUntil<T, C, D>
+where
+    T: Iterator,
+{
+    pub(crate) fn new(tournament: Tournament<T, C, D>, deadline: std::time::Instant) -> Self {
+        TakeUntil {
+            tournament,
+            deadline,
+        }
+    }
+
+    /// The number of sources that still have elements left to merge.
+    pub fn active_sources(&self) -> usize {
+        self.tournament.active_sources()
+    }
+
+    /// Take back the tournament, to continue the merge past the deadline.
+    pub fn into_inner(self) -> Tournament<T, C, D> {
+        self.tournament
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T, C, const D: usize> Iterator for TakeUntil<T, C, D>
+where
+    T: Iterator,
+    C: Comparator<T::Item>,
+{
+    type Item = T::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if std::time::Instant::now() >= self.deadline {
+            return None;
+        }
+        self.tournament.next()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use streaming_iterator::{StreamingIterator, StreamingIteratorMut};
+
+    use rand::Rng;
+
+    use crate::{by_key, MergeStats, MinComparator, Tournament, UnsortedError};
+
+    #[test]
+    fn test_chunks() {
+        let t = Tournament::from_iters_min([0..3, 3..7, 7..10]);
+        let chunks = t.chunks(4).collect::<Vec<_>>();
+
+        assert_eq!(chunks.iter().map(Vec::len).collect::<Vec<_>>(), [4, 4, 2]);
+        assert_eq!(chunks.concat(), (0..10).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_step_by() {
+        let t = Tournament::from_iters_min([0..5, 5..10]);
+        assert_eq!(t.step_by(2).collect::<Vec<_>>(), [0, 2, 4, 6, 8]);
+
+        let mut t = Tournament::from_iters_min([0..3, 4..10]).step_by(4);
+        assert_eq!(t.next(), Some(0));
+        assert_eq!(t.active_sources(), 2);
+        assert_eq!(t.next(), Some(5));
+        assert_eq!(t.active_sources(), 1);
+        assert_eq!(t.collect::<Vec<_>>(), [9]);
+    }
+
+    #[test]
+    fn test_dedup_keep() {
+        let t = Tournament::from_iters_min([[1; 5], [2; 5], [3; 5]].map(IntoIterator::into_iter));
+        assert_eq!(t.dedup_keep(2).collect::<Vec<_>>(), [1, 1, 2, 2, 3, 3]);
+
+        let t = Tournament::from_iters_min([vec![1, 1, 2], vec![1, 3, 3]].map(Vec::into_iter));
+        assert_eq!(t.clone().dedup_keep(1).collect::<Vec<_>>(), [1, 2, 3]);
+        assert_eq!(t.dedup_keep(2).collect::<Vec<_>>(), [1, 1, 2, 3, 3]);
+    }
+
+    #[test]
+    fn test_limit() {
+        let mut t = Tournament::from_iters_min([0..4, 2..6]).limit(3);
+        assert_eq!(t.remaining_budget(), 3);
+        assert_eq!(t.by_ref().collect::<Vec<_>>(), [0, 1, 2]);
+        assert_eq!(t.remaining_budget(), 0);
+        assert_eq!(t.peek(), None);
+        assert_eq!(t.next(), None);
+        assert_eq!(t.active_sources(), 2);
+        assert_eq!(t.into_inner().next(), Some(2));
+
+        let t = Tournament::from_iters_min([0..1, 1..2]).limit(3);
+        assert_eq!(t.size_hint(), (2, Some(2)));
+        assert_eq!(t.collect::<Vec<_>>(), [0, 1]);
+
+        // Polling past the end of the sources does not use up the budget
+        let mut t = Tournament::from_iters_min([0..2, 0..0]).limit(5);
+        assert_eq!(t.size_hint(), (2, Some(2)));
+        for _ in 0..5 {
+            t.next();
+        }
+        assert_eq!(t.remaining_budget(), 3);
+        assert_eq!(t.size_hint(), (0, Some(0)));
+
+        let t = Tournament::from_iters_min([0..10, 0..10]).limit(5);
+        assert_eq!(t.size_hint(), (5, Some(5)));
+    }
+
+    #[test]
+    fn test_inspect_source() {
+        let mut counts = vec![0; 4];
+        let mut t = Tournament::from_iters_min([0..10, 0..0, 5..8]);
+        t.push_iter(7..9);
+        let merged = t
+            .inspect_source(|source, _| counts[source] += 1)
+            .collect::<Vec<_>>();
+
+        assert_eq!(merged.len(), 15);
+        assert_eq!(counts, [10, 0, 3, 2]);
+    }
+
+    #[test]
+    fn test_checked() {
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..100 {
+            let mut sources = (0..rng.gen_range(1..6))
+                .map(|_| {
+                    let mut v = (0..rng.gen_range(0..20))
+                        .map(|_| rng.gen_range(1..50))
+                        .collect::<Vec<u32>>();
+                    v.sort();
+                    v
+                })
+                .collect::<Vec<_>>();
+            // Break the order of one source, unless it was empty
+            let broken = rng.gen_range(0..sources.len());
+            sources[broken].push(0);
+            let position = sources[broken].len() - 1;
+
+            let t = Tournament::from_iters_min(sources.into_iter().map(Vec::into_iter));
+            let results = t.checked().collect::<Vec<_>>();
+            let (last, merged) = results.split_last().unwrap();
+            let merged = merged
+                .iter()
+                .map(|r| *r.as_ref().unwrap())
+                .collect::<Vec<_>>();
+            assert!(merged.windows(2).all(|w| w[0] <= w[1]));
+            if position == 0 {
+                assert!(last.is_ok());
+            } else {
+                assert_eq!(*last, Err(UnsortedError::new(broken, position)));
+            }
+        }
+
+        // Sources that are not ahead of the violation keep merging until it
+        let t =
+            Tournament::from_iters_min([vec![1, 5, 2], vec![], vec![2, 3, 4]].map(Vec::into_iter));
+        assert_eq!(
+            t.checked().collect::<Vec<_>>(),
+            [
+                Ok(1),
+                Ok(2),
+                Ok(3),
+                Ok(4),
+                Ok(5),
+                Err(UnsortedError::new(0, 2))
+            ]
+        );
+
+        // A tournament consumed from the back is checked from the front
+        let mut t = Tournament::from_iters_min([vec![1, 4, 2, 6], vec![3, 5]].map(Vec::into_iter));
+        assert_eq!(t.next_back(), Some(6));
+        assert_eq!(
+            t.checked().collect::<Vec<_>>(),
+            [Ok(1), Ok(3), Ok(4), Err(UnsortedError::new(0, 2))]
+        );
+    }
+
+    #[test]
+    fn test_take_while_key() {
+        let mut t = Tournament::from_iters_min(
+            [vec![1, 10, 20, 30], vec![5, 15, 25], vec![20, 21]].map(Vec::into_iter),
+        );
+        let mut below = t.take_while_key(|&i| i < 20);
+        assert_eq!(below.by_ref().collect::<Vec<_>>(), [1, 5, 10, 15]);
+        assert_eq!(below.active_sources(), 3);
+        assert_eq!(below.next(), None);
+
+        // The boundary element is still in the tournament
+        assert_eq!(t.take_while_key(|&i| i < 20).next(), None);
+        assert_eq!(
+            t.take_while_key(|&i| i <= 25).collect::<Vec<_>>(),
+            [20, 20, 21, 25]
+        );
+        assert_eq!(t.collect::<Vec<_>>(), [30]);
+    }
+
+    #[test]
+    fn test_coalesce() {
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..100 {
+            let sources = (0..rng.gen_range(0..8))
+                .map(|_| {
+                    let mut v = (0..rng.gen_range(0..100))
+                        .map(|_| rng.gen_range(0..200))
+                        .collect::<Vec<u32>>();
+                    v.sort();
+                    v
+                })
+                .collect::<Vec<_>>();
+            let mut expected = sources.concat();
+            expected.sort();
+
+            let t = Tournament::from_iters_min(sources.clone().into_iter().map(Vec::into_iter));
+            let max_run = rng.gen_range(1..10);
+            assert_eq!(t.coalesce(max_run).collect::<Vec<_>>(), expected);
+
+            // Runs break ties like the tournament does
+            let sources = sources
+                .iter()
+                .enumerate()
+                .map(|(source, v)| v.iter().map(move |&i| (i, source)));
+            let t =
+                Tournament::from_iters_stable(sources.clone(), by_key(|&(i, _): &(u32, usize)| i));
+            let expected = t.collect::<Vec<_>>();
+            let t =
+                Tournament::from_iters_stable(sources.clone(), by_key(|&(i, _): &(u32, usize)| i));
+            assert_eq!(t.coalesce(max_run).collect::<Vec<_>>(), expected);
+
+            // Without a tie break, draws go the same way as in the tournament too
+            let t = Tournament::from_iters(sources.clone(), by_key(|&(i, _): &(u32, usize)| i));
+            let expected = t.collect::<Vec<_>>();
+            let t = Tournament::from_iters(sources, by_key(|&(i, _): &(u32, usize)| i));
+            assert_eq!(t.coalesce(max_run).collect::<Vec<_>>(), expected);
+        }
+    }
+
+    #[test]
+    fn test_lazy() {
+        let pulled = Cell::new(0);
+        let built = Cell::new(0);
+        let sources = (0..3).map(|i| {
+            built.set(built.get() + 1);
+            (i..i + 2).inspect(|_| pulled.set(pulled.get() + 1))
+        });
+
+        let mut t = Tournament::from_iters_lazy(sources, MinComparator::default());
+        assert!(!t.is_started());
+        assert_eq!((built.get(), pulled.get()), (0, 0));
+
+        assert_eq!(t.next(), Some(0));
+        assert!(t.is_started());
+        // Every source has its first element pulled, and the winner its second
+        assert_eq!((built.get(), pulled.get()), (3, 4));
+        assert_eq!(t.tournament().active_sources(), 3);
+        assert_eq!(t.collect::<Vec<_>>(), [1, 1, 2, 2, 3]);
+        assert_eq!(pulled.get(), 6);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_take_until() {
+        use std::time::{Duration, Instant};
+
+        // A deadline that has passed lets nothing through
+        let t = Tournament::from_iters_min([(0..5), (2..4)]);
+        let mut t = t.take_until(Instant::now());
+        assert_eq!(t.next(), None);
+        assert_eq!(t.active_sources(), 2);
+
+        // A deadline far in the future lets everything through
+        let mut t = t
+            .into_inner()
+            .take_until(Instant::now() + Duration::from_secs(3600));
+        assert_eq!(t.by_ref().collect::<Vec<_>>(), [0, 1, 2, 2, 3, 3, 4]);
+
+        // Pulling the next element sleeps past the deadline, so at most the
+        // element that was buffered before it is yielded
+        let slow = (0..5).inspect(|&i| {
+            if i > 0 {
+                std::thread::sleep(Duration::from_millis(50));
+            }
+        });
+        let t = Tournament::from_iters_min([slow]);
+        let mut t = t.take_until(Instant::now() + Duration::from_millis(10));
+        let yielded = t.by_ref().collect::<Vec<_>>();
+        assert!(yielded.len() <= 1, "{yielded:?}");
+        assert_eq!(t.next(), None);
+
+        // The merge resumes where it stopped
+        let rest = t.into_inner().collect::<Vec<_>>();
+        assert_eq!(rest, (yielded.len() as i32..5).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_with_stats() {
+        let t = Tournament::from_iters_min([vec![1, 5], vec![], vec![2, 3, 4]].map(Vec::into_iter));
+        let mut t = t.with_stats();
+        assert_eq!(
+            t.stats(),
+            &MergeStats {
+                emitted: 0,
+                per_source_counts: vec![0, 0, 0],
+                max_heap_len: 2,
+            }
+        );
+
+        assert_eq!(t.by_ref().take(3).collect::<Vec<_>>(), [1, 2, 3]);
+        assert_eq!(t.stats().emitted, 3);
+        assert_eq!(t.stats().per_source_counts, [1, 0, 2]);
+
+        assert_eq!(t.by_ref().collect::<Vec<_>>(), [4, 5]);
+        let (t, stats) = t.into_inner();
+        assert!(t.is_empty());
+        assert_eq!(
+            stats,
+            MergeStats {
+                emitted: 5,
+                per_source_counts: vec![2, 0, 3],
+                max_heap_len: 2,
+            }
+        );
+    }
+
+    #[test]
+    fn test_lending() {
+        let t = Tournament::from_iters_min([vec![1, 4], vec![2, 3]].map(Vec::into_iter));
+        let mut t = t.lending();
+        assert_eq!(t.get(), None);
+        assert_eq!(t.next(), Some(&1));
+        assert_eq!(t.get(), Some(&1));
+
+        // The lent element can be changed in place, and is dropped on advancing
+        *t.get_mut().unwrap() = 10;
+        assert_eq!(t.get(), Some(&10));
+        assert_eq!(t.next(), Some(&2));
+        assert_eq!(t.active_sources(), 2);
+
+        let t = t.into_inner();
+        assert_eq!(t.collect::<Vec<_>>(), [3, 4]);
+    }
+
+    #[test]
+    fn test_ranked() {
+        let mut rng = rand::thread_rng();
+
+        let sources = (0..rng.gen_range(0..10))
+            .map(|_| {
+                let mut v = (0..rng.gen_range(0..100))
+                    .map(|_| rng.gen())
+                    .collect::<Vec<u16>>();
+                v.sort();
+                v
+            })
+            .collect::<Vec<_>>();
+        let mut expected = sources.concat();
+        expected.sort();
+
+        let mut t = Tournament::from_iters_min(sources.into_iter().map(Vec::into_iter)).ranked();
+        assert_eq!(t.next_rank(), 0);
+        let (ranks, merged): (Vec<_>, Vec<_>) = t.by_ref().unzip();
+        assert_eq!(ranks, (0..expected.len()).collect::<Vec<_>>());
+        assert_eq!(merged, expected);
+        assert_eq!(t.next_rank(), expected.len());
+    }
+
+    #[test]
+    fn test_runs() {
+        let t = Tournament::from_iters_min(
+            [vec![1, 2, 7, 9, 10], vec![], vec![3, 4, 5, 6, 8]].map(Vec::into_iter),
+        );
+        let mut runs = t.runs();
+        assert_eq!(runs.next(), Some((0, vec![1, 2])));
+        assert_eq!(runs.active_sources(), 2);
+        assert_eq!(
+            runs.collect::<Vec<_>>(),
+            [
+                (2, vec![3, 4, 5, 6]),
+                (0, vec![7]),
+                (2, vec![8]),
+                (0, vec![9, 10])
+            ]
+        );
+
+        let t = Tournament::from_iters_min([1..4, 4..4]);
+        assert_eq!(t.runs().collect::<Vec<_>>(), [(0, vec![1, 2, 3])]);
+    }
+
+    #[test]
+    fn test_map_monotonic() {
+        let t = Tournament::from_iters_min([vec![1i32, 3, 5], vec![2, 3, 4]].map(Vec::into_iter));
+        let mapped = t.map_monotonic(|i| i as i64 * 1_000_000_000_000);
+        assert_eq!(mapped.active_sources(), 2);
+        assert_eq!(
+            mapped.collect::<Vec<_>>(),
+            [1, 2, 3, 3, 4, 5].map(|i| i * 1_000_000_000_000i64)
+        );
+
+        let t = Tournament::from_iters_min([vec![1i32, 3, 5], vec![2, 3, 4]].map(Vec::into_iter));
+        let union = t
+            .map_monotonic(|i| i as i64 / 2)
+            .union(MinComparator::default());
+        assert_eq!(union.collect::<Vec<_>>(), [0, 1, 2]);
+    }
+
+    #[test]
+    fn test_group_by_key() {
+        let t = Tournament::from_iters(
+            [
+                vec![(1, "a"), (2, "b"), (4, "c")],
+                vec![(1, "d"), (3, "e"), (4, "f")],
+                vec![(4, "g")],
+            ]
+            .map(Vec::into_iter),
+            by_key(|&(key, _): &(i32, &str)| key),
+        );
+        let groups = t
+            .group_by_key(|&(key, _)| key)
+            .map(|(key, group)| {
+                // Elements with equal keys may come from their sources in any order
+                let mut values = group.into_iter().map(|(_, v)| v).collect::<Vec<_>>();
+                values.sort();
+                (key, values.concat())
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            groups,
+            [
+                (1, "ad".to_string()),
+                (2, "b".to_string()),
+                (3, "e".to_string()),
+                (4, "cfg".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn test_peekable() {
+        let mut t =
+            Tournament::from_iters_min([vec![1, 3], vec![2]].map(Vec::into_iter)).peekable();
+        assert_eq!(t.peek(), Some(&1));
+        assert_eq!(t.active_sources(), 2);
+        assert_eq!(t.next(), Some(1));
+
+        if let Some(head) = t.peek_mut() {
+            *head *= 10;
+        }
+        assert_eq!(t.collect::<Vec<_>>(), [20, 3]);
+
+        let mut t = Tournament::from_iters_min([0..0, 0..0]).peekable();
+        assert_eq!(t.peek(), None);
+        assert_eq!(t.next(), None);
+    }
+
+    #[test]
+    fn test_peek_many() {
+        let mut t =
+            Tournament::from_iters_min([vec![1, 4, 6], vec![2, 3]].map(Vec::into_iter)).peekable();
+        assert_eq!(t.peek_many(0), []);
+        assert_eq!(t.peek_many(2), [1, 2]);
+        assert_eq!(t.active_sources(), 2);
+        assert_eq!(t.next(), Some(1));
+        assert_eq!(t.peek(), Some(&2));
+        assert_eq!(t.peek_many(3), [2, 3, 4]);
+        assert_eq!(t.active_sources(), 1);
+        assert_eq!(t.next(), Some(2));
+        assert_eq!(t.peek_many(10), [3, 4, 6]);
+        assert_eq!(t.collect::<Vec<_>>(), [3, 4, 6]);
+    }
+
+    #[test]
+    fn test_filter_peek() {
+        let t = Tournament::from_iters_min([(1..6), (3..9)]);
+        let mut evens = t.filter(|i| i % 2 == 0);
+        assert_eq!(evens.peek(), Some(&2));
+        assert_eq!(evens.next(), Some(2));
+        assert_eq!(evens.peek(), Some(&4));
+        assert_eq!(evens.peek(), Some(&4));
+        assert_eq!(evens.active_sources(), 2);
+        assert_eq!(evens.collect::<Vec<_>>(), [4, 4, 6, 8]);
+
+        let t = Tournament::from_iters_min([(1..6), (3..9)]);
+        let mut halves = t.filter_map(|i| (i % 2 == 0).then_some(i / 2));
+        assert_eq!(halves.peek(), Some(&1));
+        assert_eq!(halves.next(), Some(1));
+        assert_eq!(halves.peek(), Some(&2));
+        assert_eq!(halves.collect::<Vec<_>>(), [2, 2, 3, 4]);
+    }
+}
@@ -0,0 +1,160 @@
+use std::cmp::Ordering;
+
+use crate::comparator::Comparator;
+use crate::dary_heap::{sift_down, sift_up};
+
+/// A tournament over a number of sources known at compile time, which keeps the
+/// sources, their current elements and the heap in fixed size arrays instead of
+/// allocating. The output is identical to that of a [`Tournament`](crate::Tournament)
+/// over the same sources, including which source wins a draw.
+///
+/// Created by [`Tournament::from_array`](crate::Tournament::from_array).
+#[derive(Clone, Debug)]
+pub struct ArrayTournament<T, C, const K: usize>
+where
+    T: Iterator,
+{
+    results: [T; K],
+    // The current element of every source, `None` once it is exhausted
+    heads: [Option<T::Item>; K],
+    // A binary heap of source indices, of which only the first `len` are in use
+    tree: [usize; K],
+    len: usize,
+    comparator: C,
+}
+
+impl<T, C, const K: usize> ArrayTournament<T, C, K>
+where
+    T: Iterator,
+    C: Comparator<T::Item>,
+{
+    pub(crate) fn new(mut results: [T; K], comparator: C) -> Self {
+        let heads = std::array::from_fn(|index| results[index].next());
+        let mut tournament = ArrayTournament {
+            results,
+            heads,
+            tree: [0; K],
+            len: 0,
+            comparator,
+        };
+
+        for index in 0..K {
+            if tournament.heads[index].is_some() {
+                tournament.tree[tournament.len] = index;
+                tournament.len += 1;
+                sift_up::<_, _, 2>(
+                    &mut tournament.tree[..tournament.len],
+                    tournament.len - 1,
+                    slot_order(&tournament.heads, &tournament.comparator),
+                );
+            }
+        }
+        tournament
+    }
+
+    /// The number of sources that still have elements left to merge.
+    pub fn active_sources(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the merge is exhausted.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+impl<T, C, const K: usize> Iterator for ArrayTournament<T, C, K>
+where
+    T: Iterator,
+    C: Comparator<T::Item>,
+{
+    type Item = T::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        let index = self.tree[0];
+        let next = self.results[index].next();
+        let item = std::mem::replace(&mut self.heads[index], next)?;
+
+        let order = slot_order(&self.heads, &self.comparator);
+        match &self.heads[index] {
+            Some(next) => {
+                debug_assert!(
+                    self.comparator.cmp(next, &item).is_ge(),
+                    "source {index} is not sorted according to the comparator"
+                );
+                // Placed after the sources it draws with, as in the heap of a tournament
+                sift_down::<_, _, 2>(&mut self.tree[..self.len], 0, |a, b| {
+                    order(a, b).then(Ordering::Less)
+                });
+            }
+            None => {
+                self.len -= 1;
+                self.tree.swap(0, self.len);
+                sift_down::<_, _, 2>(&mut self.tree[..self.len], 0, order);
+            }
+        }
+
+        Some(item)
+    }
+}
+
+/// The order of the source indices in the tree, by comparing their current elements.
+fn slot_order<'a, I, C>(
+    heads: &'a [Option<I>],
+    comparator: &'a C,
+) -> impl Fn(&usize, &usize) -> Ordering + 'a
+where
+    C: Comparator<I>,
+{
+    move |&a, &b| {
+        comparator.cmp(
+            heads[a]
+                .as_ref()
+                .expect("every source in the tree has an element"),
+            heads[b]
+                .as_ref()
+                .expect("every source in the tree has an element"),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::Rng;
+
+    use crate::{by_key, MinComparator, Tournament};
+
+    #[test]
+    fn test_array_matches_heap() {
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..100 {
+            let sources = [0, 1, 2, 3, 4].map(|tag| {
+                let mut v = (0..rng.gen_range(0..50))
+                    .map(|_| (rng.gen_range(0..20), tag))
+                    .collect::<Vec<(u32, u32)>>();
+                v.sort();
+                v
+            });
+
+            let comparator = by_key(|&(key, _): &(u32, u32)| key);
+            let heap = Tournament::from_iters(sources.clone().map(Vec::into_iter), comparator)
+                .collect::<Vec<_>>();
+            let array = Tournament::from_array(sources.map(Vec::into_iter), comparator);
+            assert_eq!(array.collect::<Vec<_>>(), heap);
+        }
+    }
+
+    #[test]
+    fn test_array_active_sources() {
+        let mut t = Tournament::from_array([1..3, 0..0, 2..6], MinComparator::default());
+        assert_eq!(t.active_sources(), 2);
+        assert_eq!(t.by_ref().take(3).collect::<Vec<_>>(), [1, 2, 2]);
+        assert_eq!(t.active_sources(), 1);
+        assert_eq!(t.by_ref().collect::<Vec<_>>(), [3, 4, 5]);
+        assert!(t.is_empty());
+    }
+}
@@ -0,0 +1,157 @@
+use crate::dary_heap::DaryHeap;
+
+/// A tournament that orders elements by a key, like a [`Tournament`](crate::Tournament)
+/// with a [`by_key`](crate::by_key) comparator, but computes the key of every
+/// element only once, when it is pulled from its source, instead of on every
+/// comparison. This pays off when the key is expensive to compute, for example
+/// when it is parsed from a string, at the cost of storing the key alongside
+/// the element.
+///
+/// The output is identical to that of
+/// [`Tournament::from_iters_by_key`](crate::Tournament::from_iters_by_key), including
+/// which source wins a draw.
+#[derive(Clone, Debug)]
+pub struct CachedKeyTournament<T, K, F>
+where
+    T: Iterator,
+{
+    tree: DaryHeap<KeyedEntry<K, T::Item>, 2>,
+    results: Vec<T>,
+    key_fn: F,
+}
+
+impl<T, K, F> CachedKeyTournament<T, K, F>
+where
+    T: Iterator,
+    K: Ord,
+    F: FnMut(&T::Item) -> K,
+{
+    /// Create a tournament from iterators that yield data from smallest to largest
+    /// key, as extracted by `key_fn`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tournament_kway::CachedKeyTournament;
+    ///
+    /// let t = CachedKeyTournament::from_iters(
+    ///     [vec!["3", "20"], vec!["10"]].map(Vec::into_iter),
+    ///     |s: &&str| s.parse::<u32>().unwrap(),
+    /// );
+    /// assert_eq!(t.collect::<Vec<_>>(), ["3", "10", "20"]);
+    /// ```
+    pub fn from_iters<I: IntoIterator<Item = T>>(iters: I, mut key_fn: F) -> Self {
+        let iters = iters.into_iter();
+        let mut tree = DaryHeap::with_capacity(iters.size_hint().0);
+        let mut results = Vec::with_capacity(iters.size_hint().0);
+
+        for mut iter in iters {
+            if let Some(item) = iter.next() {
+                let entry = KeyedEntry {
+                    key: key_fn(&item),
+                    item,
+                    index: results.len(),
+                };
+                tree.push(entry, |a, b| a.key.cmp(&b.key));
+                results.push(iter);
+            }
+        }
+        results.shrink_to_fit();
+
+        CachedKeyTournament {
+            tree,
+            results,
+            key_fn,
+        }
+    }
+
+    /// The number of sources that still have elements left to merge.
+    pub fn active_sources(&self) -> usize {
+        self.tree.len()
+    }
+}
+
+impl<T, K, F> Iterator for CachedKeyTournament<T, K, F>
+where
+    T: Iterator,
+    K: Ord,
+    F: FnMut(&T::Item) -> K,
+{
+    type Item = T::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let top = self.tree.peek_mut()?;
+
+        match self.results[top.index].next() {
+            Some(next) => {
+                let key = (self.key_fn)(&next);
+                debug_assert!(
+                    key >= top.key,
+                    "source {} is not sorted by its key",
+                    top.index
+                );
+                top.key = key;
+                let item = std::mem::replace(&mut top.item, next);
+                self.tree.sift_down_top(|a, b| a.key.cmp(&b.key));
+                Some(item)
+            }
+            None => self.tree.pop(|a, b| a.key.cmp(&b.key)).map(|e| e.item),
+        }
+    }
+}
+
+/// An entry into the tree, holding the current element of a source and its key
+#[derive(Clone, Debug)]
+struct KeyedEntry<K, I> {
+    key: K,
+    item: I,
+    index: usize,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use rand::Rng;
+
+    use crate::{CachedKeyTournament, Tournament};
+
+    #[test]
+    fn test_cached_key_matches_by_key() {
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..100 {
+            let sources = (0..rng.gen_range(0..8))
+                .map(|tag| {
+                    let mut v = (0..rng.gen_range(0..50))
+                        .map(|_| (rng.gen_range(0..20), tag))
+                        .collect::<Vec<(u32, u32)>>();
+                    v.sort();
+                    v
+                })
+                .collect::<Vec<_>>();
+
+            let by_key = Tournament::from_iters_by_key(
+                sources.iter().map(|v| v.iter()),
+                |&&(key, _): &&(u32, u32)| key,
+            )
+            .collect::<Vec<_>>();
+            let cached = CachedKeyTournament::from_iters(sources.iter().map(|v| v.iter()), |e| e.0)
+                .collect::<Vec<_>>();
+
+            assert_eq!(cached, by_key);
+        }
+    }
+
+    #[test]
+    fn test_key_computed_once() {
+        let calls = Cell::new(0);
+        let t = CachedKeyTournament::from_iters([(0..100), (50..200), (10..20)], |&i| {
+            calls.set(calls.get() + 1);
+            i
+        });
+
+        assert_eq!(t.count(), 260);
+        assert_eq!(calls.get(), 260);
+    }
+}
@@ -1,12 +1,124 @@
 use core::cmp::Ordering;
-use std::marker::PhantomData;
+use std::{
+    fmt,
+    marker::PhantomData,
+    rc::Rc,
+    sync::{
+        atomic::{AtomicUsize, Ordering as AtomicOrdering},
+        Arc,
+    },
+};
 
 /// A [`Comparator`] can compare two items in the tournament to decide which is the winner.
 pub trait Comparator<I: ?Sized> {
     /// Compare two results in the tournament, the winner is the smaller of the two as decide
     /// by [`Ordering::Less`]. [`Ordering::Equal`] is concidered a draw, and either contestant
-    /// may be chosen as the winner.
+    /// may be chosen as the winner, unless the tournament was created with
+    /// [`Tournament::from_iters_stable`](crate::Tournament::from_iters_stable).
     fn cmp(&self, a: &I, b: &I) -> Ordering;
+
+    /// Compare by this comparator, breaking its draws with `other`, as with
+    /// [`ThenBy`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tournament_kway::{by_key, Comparator, MaxComparator};
+    ///
+    /// let c = by_key(|s: &&str| s.len()).then_by(MaxComparator::default());
+    /// assert!(c.cmp(&"b", &"a").is_lt());
+    /// assert!(c.cmp(&"b", &"aa").is_lt());
+    /// ```
+    fn then_by<B: Comparator<I>>(self, other: B) -> ThenBy<Self, B>
+    where
+        Self: Sized,
+    {
+        ThenBy::new(self, other)
+    }
+
+    /// Adapt the comparator to items of type `U`, by comparing what `g` projects
+    /// them to. This allows a comparator to be reused for wrapper types.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tournament_kway::{Comparator, MinComparator, Tournament};
+    ///
+    /// struct Id(u32);
+    ///
+    /// let t = Tournament::from_iters(
+    ///     [vec![Id(1), Id(4)], vec![Id(2)]].map(Vec::into_iter),
+    ///     MinComparator::default().map_input(|id: &Id| &id.0),
+    /// );
+    /// assert_eq!(t.map(|id| id.0).collect::<Vec<_>>(), [1, 2, 4]);
+    /// ```
+    fn map_input<U, G>(self, g: G) -> MapInput<Self, G, I>
+    where
+        Self: Sized,
+        U: ?Sized,
+        G: Fn(&U) -> &I,
+    {
+        MapInput {
+            comparator: self,
+            g,
+            _p: PhantomData,
+        }
+    }
+}
+
+impl<I: ?Sized, C: ?Sized + Comparator<I>> Comparator<I> for &C {
+    #[inline(always)]
+    fn cmp(&self, a: &I, b: &I) -> Ordering {
+        (**self).cmp(a, b)
+    }
+}
+
+/// Allows the comparator to be chosen at runtime, with `Box<dyn Comparator<I>>`.
+///
+/// # Examples
+///
+/// ```
+/// use tournament_kway::{Comparator, MaxComparator, MinComparator, Tournament};
+///
+/// let ascending = false;
+/// let comparator: Box<dyn Comparator<i32>> = if ascending {
+///     Box::new(MinComparator::default())
+/// } else {
+///     Box::new(MaxComparator::default())
+/// };
+///
+/// let t = Tournament::from_iters([(1..3).rev(), (0..2).rev()], comparator);
+/// assert_eq!(t.collect::<Vec<_>>(), [2, 1, 1, 0]);
+/// ```
+impl<I: ?Sized, C: ?Sized + Comparator<I>> Comparator<I> for Box<C> {
+    #[inline(always)]
+    fn cmp(&self, a: &I, b: &I) -> Ordering {
+        (**self).cmp(a, b)
+    }
+}
+
+/// Allows an expensive comparator to be shared cheaply, for example between clones
+/// of a tournament.
+impl<I: ?Sized, C: ?Sized + Comparator<I>> Comparator<I> for Arc<C> {
+    #[inline(always)]
+    fn cmp(&self, a: &I, b: &I) -> Ordering {
+        (**self).cmp(a, b)
+    }
+}
+
+/// A [`Comparator`] of references that compares the referenced items with an
+/// inner comparator. This allows a comparator of unsized items, such as [`str`]
+/// or `[u8]`, to merge borrowed items.
+///
+/// Usually created by [`Tournament::from_iters_ref`](crate::Tournament::from_iters_ref).
+#[derive(Clone, Copy, Debug, Default)]
+pub struct RefComparator<C>(pub C);
+
+impl<'a, I: ?Sized, C: Comparator<I>> Comparator<&'a I> for RefComparator<C> {
+    #[inline(always)]
+    fn cmp(&self, a: &&'a I, b: &&'a I) -> Ordering {
+        self.0.cmp(*a, *b)
+    }
 }
 
 /// A [`Comparator`] that choses the smaller result of the two
@@ -29,6 +141,12 @@ impl<I: ?Sized + Ord> Clone for MinComparator<I> {
     }
 }
 
+impl<I: ?Sized + Ord> fmt::Debug for MinComparator<I> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("MinComparator")
+    }
+}
+
 impl<I: ?Sized + Ord> Comparator<I> for MinComparator<I> {
     #[inline(always)]
     fn cmp(&self, a: &I, b: &I) -> Ordering {
@@ -37,6 +155,20 @@ impl<I: ?Sized + Ord> Comparator<I> for MinComparator<I> {
 }
 
 /// A [`Comparator`] that choses the larger result of the two
+///
+/// Merging items wrapped in [`std::cmp::Reverse`] with a [`MinComparator`] is
+/// equivalent, which is convenient when the items are already wrapped, as they
+/// often are for use with [`std::collections::BinaryHeap`].
+///
+/// # Examples
+///
+/// ```
+/// use std::cmp::Reverse;
+/// use tournament_kway::Tournament;
+///
+/// let t = Tournament::from_iters_min([vec![Reverse(5), Reverse(1)], vec![Reverse(3)]].map(Vec::into_iter));
+/// assert_eq!(t.map(|Reverse(i)| i).collect::<Vec<_>>(), [5, 3, 1]);
+/// ```
 #[derive(Copy)]
 pub struct MaxComparator<I: ?Sized + Ord> {
     _p: PhantomData<I>,
@@ -56,9 +188,858 @@ impl<I: ?Sized + Ord> Clone for MaxComparator<I> {
     }
 }
 
+impl<I: ?Sized + Ord> fmt::Debug for MaxComparator<I> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("MaxComparator")
+    }
+}
+
 impl<I: ?Sized + Ord> Comparator<I> for MaxComparator<I> {
     #[inline(always)]
     fn cmp(&self, a: &I, b: &I) -> Ordering {
         b.cmp(a)
     }
 }
+
+/// A [`Comparator`] that choses the smaller or the larger result of the two, as
+/// decided at runtime. This allows ascending and descending merges to share a
+/// single tournament type.
+#[derive(Clone, Copy, Debug)]
+pub enum DirectionComparator<I: ?Sized + Ord> {
+    /// Rate entries from smallest to largest, as with [`MinComparator`]
+    Min(MinComparator<I>),
+    /// Rate entries from largest to smallest, as with [`MaxComparator`]
+    Max(MaxComparator<I>),
+}
+
+impl<I: ?Sized + Ord> DirectionComparator<I> {
+    /// A comparator that rates entries from smallest to largest if `ascending` is
+    /// true, and from largest to smallest otherwise.
+    pub fn new(ascending: bool) -> Self {
+        if ascending {
+            DirectionComparator::Min(MinComparator::default())
+        } else {
+            DirectionComparator::Max(MaxComparator::default())
+        }
+    }
+}
+
+impl<I: ?Sized + Ord> Comparator<I> for DirectionComparator<I> {
+    #[inline(always)]
+    fn cmp(&self, a: &I, b: &I) -> Ordering {
+        match self {
+            DirectionComparator::Min(c) => c.cmp(a, b),
+            DirectionComparator::Max(c) => c.cmp(a, b),
+        }
+    }
+}
+
+/// A [`Comparator`] for [`Option`]s, that compares `Some` results with an inner
+/// comparator, and places `None` either before or after all of them.
+///
+/// # Examples
+///
+/// ```
+/// use tournament_kway::{MinComparator, OptionComparator, Tournament};
+///
+/// let t = Tournament::from_iters(
+///     [vec![Some(1), Some(4), None], vec![Some(2), None]].map(Vec::into_iter),
+///     OptionComparator::new(MinComparator::default(), false),
+/// );
+/// assert_eq!(t.collect::<Vec<_>>(), [Some(1), Some(2), Some(4), None, None]);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct OptionComparator<C> {
+    inner: C,
+    none_first: bool,
+}
+
+impl<C> OptionComparator<C> {
+    /// Compare `Some` results with `inner`, placing `None` before them if
+    /// `none_first` is true, and after them otherwise.
+    pub fn new(inner: C, none_first: bool) -> Self {
+        OptionComparator { inner, none_first }
+    }
+}
+
+impl<I, C: Comparator<I>> Comparator<Option<I>> for OptionComparator<C> {
+    #[inline(always)]
+    fn cmp(&self, a: &Option<I>, b: &Option<I>) -> Ordering {
+        let none = if self.none_first {
+            Ordering::Less
+        } else {
+            Ordering::Greater
+        };
+        match (a, b) {
+            (Some(a), Some(b)) => self.inner.cmp(a, b),
+            (None, None) => Ordering::Equal,
+            (None, Some(_)) => none,
+            (Some(_), None) => none.reverse(),
+        }
+    }
+}
+
+/// A [`Comparator`] that choses the smaller of two byte strings in lexicographic
+/// order, the same order as [`Ord`] for `[u8]`. It is the recommended comparator for
+/// merging binary keys, which tend to share long prefixes.
+///
+/// The comparison is that of `[u8]`, which the standard library implements with
+/// `memcmp` and is faster than comparing the keys a word at a time. Use it with
+/// [`Tournament::from_iters_ref`](crate::Tournament::from_iters_ref) to merge
+/// `&[u8]` keys without copying them, or directly for `&[u8]` and `Vec<u8>` items.
+///
+/// # Examples
+///
+/// ```
+/// use tournament_kway::{BytesComparator, Tournament};
+///
+/// let a: &[&[u8]] = &[b"key:0001", b"key:0003"];
+/// let b: &[&[u8]] = &[b"key:0002"];
+/// let t = Tournament::from_iters_ref([a.iter().copied(), b.iter().copied()], BytesComparator);
+/// assert_eq!(t.collect::<Vec<_>>(), [b"key:0001", b"key:0002", b"key:0003"]);
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct BytesComparator;
+
+impl Comparator<[u8]> for BytesComparator {
+    #[inline(always)]
+    fn cmp(&self, a: &[u8], b: &[u8]) -> Ordering {
+        a.cmp(b)
+    }
+}
+
+impl Comparator<&[u8]> for BytesComparator {
+    #[inline(always)]
+    fn cmp(&self, a: &&[u8], b: &&[u8]) -> Ordering {
+        a.cmp(b)
+    }
+}
+
+impl Comparator<Vec<u8>> for BytesComparator {
+    #[inline(always)]
+    fn cmp(&self, a: &Vec<u8>, b: &Vec<u8>) -> Ordering {
+        a.cmp(b)
+    }
+}
+
+/// A [`Comparator`] that choses the smaller of two [`f64`] results, using the
+/// total order of [`f64::total_cmp`]. Positive NaNs are ordered after every other
+/// value, and negative NaNs before.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct TotalF64Comparator;
+
+impl Comparator<f64> for TotalF64Comparator {
+    #[inline(always)]
+    fn cmp(&self, a: &f64, b: &f64) -> Ordering {
+        a.total_cmp(b)
+    }
+}
+
+/// A [`Comparator`] that choses the smaller of two [`f32`] results, using the
+/// total order of [`f32::total_cmp`]. Positive NaNs are ordered after every other
+/// value, and negative NaNs before.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct TotalF32Comparator;
+
+impl Comparator<f32> for TotalF32Comparator {
+    #[inline(always)]
+    fn cmp(&self, a: &f32, b: &f32) -> Ordering {
+        a.total_cmp(b)
+    }
+}
+
+/// A [`Comparator`] that rates two [`f64`] results as equal when they are at most
+/// `epsilon` apart, and otherwise choses the smaller one, using the total order of
+/// [`f64::total_cmp`]. It lets near-equal values draw, for example to drop them
+/// with [`Tournament::union`](crate::Tournament::union).
+///
+/// This is not a total order, as equality is not transitive: `0.0` and `0.8`
+/// are both equal to `0.4` with an `epsilon` of `0.5`, but not to each other. It is
+/// safe for merging and dropping duplicates, where it only decides the order of
+/// neighboring elements, but not where a consistent order is assumed, such as
+/// for sorting or a binary search.
+///
+/// # Examples
+///
+/// ```
+/// use tournament_kway::{ApproxComparator, Tournament};
+///
+/// let t = Tournament::from_iters(
+///     [vec![0.1, 0.2], vec![0.1 + 1e-12, 0.3]].map(Vec::into_iter),
+///     ApproxComparator::new(1e-9),
+/// );
+/// assert_eq!(t.union().count(), 3);
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ApproxComparator {
+    epsilon: f64,
+}
+
+impl ApproxComparator {
+    /// Rate results that are at most `epsilon` apart as equal.
+    pub fn new(epsilon: f64) -> Self {
+        ApproxComparator { epsilon }
+    }
+}
+
+impl Comparator<f64> for ApproxComparator {
+    #[inline(always)]
+    fn cmp(&self, a: &f64, b: &f64) -> Ordering {
+        if (a - b).abs() <= self.epsilon {
+            return Ordering::Equal;
+        }
+        a.total_cmp(b)
+    }
+}
+
+/// A [`Comparator`] that choses the result with the smaller key, as extracted by a
+/// key function.
+///
+/// Created by [`by_key`].
+pub struct ByKey<I: ?Sized, K, F> {
+    f: F,
+    _p: PhantomData<fn(&I) -> K>,
+}
+
+/// Create a [`Comparator`] that orders results by the key extracted with `f`.
+///
+/// # Examples
+///
+/// ```
+/// use tournament_kway::{by_key, Tournament};
+///
+/// let t = Tournament::from_iters(
+///     [vec![(1, 'b'), (3, 'a')], vec![(2, 'c')]].map(Vec::into_iter),
+///     by_key(|&(n, _): &(i32, char)| n),
+/// );
+/// assert_eq!(t.map(|(_, c)| c).collect::<String>(), "bca");
+/// ```
+pub fn by_key<I: ?Sized, K: Ord, F: Fn(&I) -> K>(f: F) -> ByKey<I, K, F> {
+    ByKey { f, _p: PhantomData }
+}
+
+impl<I: ?Sized, K, F: Clone> Clone for ByKey<I, K, F> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        ByKey {
+            f: self.f.clone(),
+            _p: PhantomData,
+        }
+    }
+}
+
+impl<I: ?Sized, K, F: Copy> Copy for ByKey<I, K, F> {}
+
+impl<I: ?Sized, K, F> fmt::Debug for ByKey<I, K, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ByKey")
+    }
+}
+
+impl<I: ?Sized, K: Ord, F: Fn(&I) -> K> Comparator<I> for ByKey<I, K, F> {
+    #[inline(always)]
+    fn cmp(&self, a: &I, b: &I) -> Ordering {
+        (self.f)(a).cmp(&(self.f)(b))
+    }
+}
+
+/// Create a [`Comparator`] that orders results by the key extracted with `f`, from
+/// the smallest key to the largest. The same as [`by_key`], named to pair with
+/// [`by_key_desc`].
+///
+/// # Examples
+///
+/// Sort by descending score, and then by ascending id:
+///
+/// ```
+/// use tournament_kway::{by_key_asc, by_key_desc, Comparator, Tournament};
+///
+/// #[derive(Clone, Copy)]
+/// struct Player {
+///     score: u32,
+///     id: u32,
+/// }
+///
+/// let order = by_key_desc(|p: &Player| p.score).then_by(by_key_asc(|p: &Player| p.id));
+/// let t = Tournament::from_iters(
+///     [
+///         vec![Player { score: 9, id: 3 }, Player { score: 5, id: 1 }],
+///         vec![Player { score: 9, id: 1 }, Player { score: 7, id: 2 }],
+///     ]
+///     .map(Vec::into_iter),
+///     order.clone(),
+/// );
+/// assert_eq!(
+///     t.map(|p| (p.score, p.id)).collect::<Vec<_>>(),
+///     [(9, 1), (9, 3), (7, 2), (5, 1)]
+/// );
+/// ```
+pub fn by_key_asc<I: ?Sized, K: Ord, F: Fn(&I) -> K>(f: F) -> ByKey<I, K, F> {
+    by_key(f)
+}
+
+/// A [`Comparator`] that choses the result with the larger key, as extracted by a
+/// key function.
+///
+/// Created by [`by_key_desc`].
+pub struct ByKeyDesc<I: ?Sized, K, F> {
+    f: F,
+    _p: PhantomData<fn(&I) -> K>,
+}
+
+/// Create a [`Comparator`] that orders results by the key extracted with `f`, from
+/// the largest key to the smallest. See [`by_key_asc`] for an example.
+pub fn by_key_desc<I: ?Sized, K: Ord, F: Fn(&I) -> K>(f: F) -> ByKeyDesc<I, K, F> {
+    ByKeyDesc { f, _p: PhantomData }
+}
+
+impl<I: ?Sized, K, F: Clone> Clone for ByKeyDesc<I, K, F> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        ByKeyDesc {
+            f: self.f.clone(),
+            _p: PhantomData,
+        }
+    }
+}
+
+impl<I: ?Sized, K, F: Copy> Copy for ByKeyDesc<I, K, F> {}
+
+impl<I: ?Sized, K, F> fmt::Debug for ByKeyDesc<I, K, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ByKeyDesc")
+    }
+}
+
+impl<I: ?Sized, K: Ord, F: Fn(&I) -> K> Comparator<I> for ByKeyDesc<I, K, F> {
+    #[inline(always)]
+    fn cmp(&self, a: &I, b: &I) -> Ordering {
+        (self.f)(b).cmp(&(self.f)(a))
+    }
+}
+
+/// A [`Comparator`] that compares results with a first comparator, and only when
+/// it declares a draw falls back to a second one.
+///
+/// Usually created with the [`lexicographic!`](crate::lexicographic) macro.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ThenBy<A, B> {
+    first: A,
+    second: B,
+}
+
+impl<A, B> ThenBy<A, B> {
+    /// Compare by `first`, breaking draws with `second`.
+    pub fn new(first: A, second: B) -> Self {
+        ThenBy { first, second }
+    }
+}
+
+impl<I: ?Sized, A: Comparator<I>, B: Comparator<I>> Comparator<I> for ThenBy<A, B> {
+    #[inline(always)]
+    fn cmp(&self, a: &I, b: &I) -> Ordering {
+        self.first.cmp(a, b).then_with(|| self.second.cmp(a, b))
+    }
+}
+
+/// A [`Comparator`] that compares results by what a function projects them to,
+/// with another comparator.
+///
+/// Created by [`Comparator::map_input`].
+pub struct MapInput<C, G, I: ?Sized> {
+    comparator: C,
+    g: G,
+    _p: PhantomData<fn(&I)>,
+}
+
+impl<C: Clone, G: Clone, I: ?Sized> Clone for MapInput<C, G, I> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        MapInput {
+            comparator: self.comparator.clone(),
+            g: self.g.clone(),
+            _p: PhantomData,
+        }
+    }
+}
+
+impl<C: Copy, G: Copy, I: ?Sized> Copy for MapInput<C, G, I> {}
+
+impl<C: fmt::Debug, G, I: ?Sized> fmt::Debug for MapInput<C, G, I> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MapInput")
+            .field("comparator", &self.comparator)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<U, I, C, G> Comparator<U> for MapInput<C, G, I>
+where
+    U: ?Sized,
+    I: ?Sized,
+    C: Comparator<I>,
+    G: Fn(&U) -> &I,
+{
+    #[inline(always)]
+    fn cmp(&self, a: &U, b: &U) -> Ordering {
+        self.comparator.cmp((self.g)(a), (self.g)(b))
+    }
+}
+
+/// Build a [`Comparator`] that compares by each of the given comparators in turn,
+/// until one of them is not a draw.
+///
+/// # Examples
+///
+/// ```
+/// use tournament_kway::{by_key, lexicographic, Tournament};
+///
+/// struct Record {
+///     date: u32,
+///     id: u32,
+/// }
+///
+/// let t = Tournament::from_iters(
+///     [
+///         vec![Record { date: 1, id: 2 }, Record { date: 2, id: 1 }].into_iter(),
+///         vec![Record { date: 1, id: 1 }].into_iter(),
+///     ],
+///     lexicographic!(by_key(|r: &Record| r.date), by_key(|r: &Record| r.id)),
+/// );
+/// assert_eq!(
+///     t.map(|r| (r.date, r.id)).collect::<Vec<_>>(),
+///     [(1, 1), (1, 2), (2, 1)]
+/// );
+/// ```
+#[macro_export]
+macro_rules! lexicographic {
+    ($comparator:expr $(,)?) => {
+        $comparator
+    };
+    ($comparator:expr, $($rest:expr),+ $(,)?) => {
+        $crate::ThenBy::new($comparator, $crate::lexicographic!($($rest),+))
+    };
+}
+
+/// A [`Comparator`] that chooses the smaller of two strings in natural order, where
+/// runs of ASCII digits are compared by their numeric value, so that `"file2"`
+/// comes before `"file10"`. Strings that only differ in leading zeros are ordered
+/// lexically.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct NaturalComparator;
+
+impl NaturalComparator {
+    fn natural_cmp(a: &[u8], b: &[u8]) -> Ordering {
+        let (mut i, mut j) = (0, 0);
+
+        while i < a.len() && j < b.len() {
+            if a[i].is_ascii_digit() && b[j].is_ascii_digit() {
+                let a_end = i + a[i..].iter().take_while(|c| c.is_ascii_digit()).count();
+                let b_end = j + b[j..].iter().take_while(|c| c.is_ascii_digit()).count();
+                let a_num = Self::trim_zeros(&a[i..a_end]);
+                let b_num = Self::trim_zeros(&b[j..b_end]);
+
+                match a_num.len().cmp(&b_num.len()).then_with(|| a_num.cmp(b_num)) {
+                    Ordering::Equal => (i, j) = (a_end, b_end),
+                    ord => return ord,
+                }
+            } else {
+                match a[i].cmp(&b[j]) {
+                    Ordering::Equal => (i, j) = (i + 1, j + 1),
+                    ord => return ord,
+                }
+            }
+        }
+
+        (a.len() - i).cmp(&(b.len() - j)).then_with(|| a.cmp(b))
+    }
+
+    fn trim_zeros(digits: &[u8]) -> &[u8] {
+        let zeros = digits.iter().take_while(|&&c| c == b'0').count();
+        &digits[zeros..]
+    }
+}
+
+impl Comparator<str> for NaturalComparator {
+    #[inline(always)]
+    fn cmp(&self, a: &str, b: &str) -> Ordering {
+        Self::natural_cmp(a.as_bytes(), b.as_bytes())
+    }
+}
+
+impl Comparator<&str> for NaturalComparator {
+    #[inline(always)]
+    fn cmp(&self, a: &&str, b: &&str) -> Ordering {
+        Self::natural_cmp(a.as_bytes(), b.as_bytes())
+    }
+}
+
+impl Comparator<String> for NaturalComparator {
+    #[inline(always)]
+    fn cmp(&self, a: &String, b: &String) -> Ordering {
+        Self::natural_cmp(a.as_bytes(), b.as_bytes())
+    }
+}
+
+/// A [`Comparator`] that delegates to an inner comparator, counting every
+/// comparison it makes. The counter is shared, so it can be read after the
+/// comparator was moved into a tournament, and clones of the comparator all
+/// count into it.
+///
+/// # Examples
+///
+/// ```
+/// use tournament_kway::{CountingComparator, MinComparator, Tournament};
+///
+/// let comparator = CountingComparator::new(MinComparator::default());
+/// let counter = comparator.counter();
+///
+/// let t = Tournament::from_iters([(0..100), (0..100)], comparator);
+/// assert_eq!(t.count(), 200);
+/// assert!(counter.load(std::sync::atomic::Ordering::Relaxed) > 0);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct CountingComparator<C> {
+    inner: C,
+    count: Arc<AtomicUsize>,
+}
+
+impl<C> CountingComparator<C> {
+    /// Wrap `inner`, starting from a count of zero.
+    pub fn new(inner: C) -> Self {
+        CountingComparator {
+            inner,
+            count: Arc::default(),
+        }
+    }
+
+    /// The shared counter of comparisons.
+    pub fn counter(&self) -> Arc<AtomicUsize> {
+        Arc::clone(&self.count)
+    }
+
+    /// The number of comparisons made so far.
+    pub fn count(&self) -> usize {
+        self.count.load(AtomicOrdering::Relaxed)
+    }
+}
+
+impl<I: ?Sized, C: Comparator<I>> Comparator<I> for CountingComparator<C> {
+    #[inline(always)]
+    fn cmp(&self, a: &I, b: &I) -> Ordering {
+        self.count.fetch_add(1, AtomicOrdering::Relaxed);
+        self.inner.cmp(a, b)
+    }
+}
+
+/// A [`Comparator`] for [`Arc`] and [`Rc`] items that rates two pointers to the same
+/// allocation as equal without looking at their values, and compares the values
+/// with `inner` otherwise. It speeds up merges where many items share their
+/// allocations, and the values are expensive to compare.
+///
+/// The comparator `inner` must rate every value as equal to itself, which is
+/// true of every order.
+///
+/// # Examples
+///
+/// ```
+/// use std::sync::Arc;
+/// use tournament_kway::{MinComparator, PtrShortCircuitComparator, Tournament};
+///
+/// let shared = Arc::new(String::from("shared"));
+/// let a = vec![Arc::new(String::from("a")), Arc::clone(&shared)];
+/// let b = vec![Arc::clone(&shared), Arc::new(String::from("z"))];
+/// let t = Tournament::from_iters(
+///     [a, b].map(Vec::into_iter),
+///     PtrShortCircuitComparator::new(MinComparator::<String>::default()),
+/// );
+/// assert_eq!(t.map(|s| s.to_string()).collect::<Vec<_>>(), ["a", "shared", "shared", "z"]);
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct PtrShortCircuitComparator<C> {
+    inner: C,
+}
+
+impl<C> PtrShortCircuitComparator<C> {
+    /// Compare the values behind pointers to different allocations with `inner`.
+    pub fn new(inner: C) -> Self {
+        PtrShortCircuitComparator { inner }
+    }
+}
+
+impl<I: ?Sized, C: Comparator<I>> Comparator<Arc<I>> for PtrShortCircuitComparator<C> {
+    #[inline(always)]
+    fn cmp(&self, a: &Arc<I>, b: &Arc<I>) -> Ordering {
+        if Arc::ptr_eq(a, b) {
+            return Ordering::Equal;
+        }
+        self.inner.cmp(a, b)
+    }
+}
+
+impl<I: ?Sized, C: Comparator<I>> Comparator<Rc<I>> for PtrShortCircuitComparator<C> {
+    #[inline(always)]
+    fn cmp(&self, a: &Rc<I>, b: &Rc<I>) -> Ordering {
+        if Rc::ptr_eq(a, b) {
+            return Ordering::Equal;
+        }
+        self.inner.cmp(a, b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        cmp::Ordering,
+        rc::Rc,
+        sync::{
+            atomic::{AtomicUsize, Ordering as AtomicOrdering},
+            Arc,
+        },
+    };
+
+    use rand::Rng;
+
+    use super::{
+        ApproxComparator, BytesComparator, Comparator, CountingComparator, MaxComparator,
+        MinComparator, NaturalComparator, OptionComparator, PtrShortCircuitComparator,
+    };
+    use crate::Tournament;
+
+    #[test]
+    fn test_natural() {
+        let cmp = |a: &str, b: &str| Comparator::<str>::cmp(&NaturalComparator, a, b);
+
+        assert_eq!(cmp("file2", "file10"), Ordering::Less);
+        assert_eq!(cmp("file10", "file9"), Ordering::Greater);
+        assert_eq!(cmp("file10b", "file10a"), Ordering::Greater);
+        assert_eq!(cmp("a1b22", "a1b3"), Ordering::Greater);
+        assert_eq!(cmp("12345678901234567890", "9"), Ordering::Greater);
+        assert_eq!(cmp("file", "file1"), Ordering::Less);
+        assert_eq!(cmp("file1", "file1"), Ordering::Equal);
+
+        assert_eq!(cmp("file002", "file10"), Ordering::Less);
+        assert_eq!(cmp("file01", "file1"), Ordering::Less);
+        assert_eq!(cmp("file1", "file01"), Ordering::Greater);
+        assert_eq!(cmp("file00", "file0"), Ordering::Greater);
+    }
+
+    #[test]
+    fn test_bytes() {
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..10000 {
+            let prefix = (0..rng.gen_range(0..20))
+                .map(|_| rng.gen())
+                .collect::<Vec<u8>>();
+            let [a, b] = [0, 1].map(|_| {
+                let mut v = prefix.clone();
+                v.extend((0..rng.gen_range(0..12)).map(|_| rng.gen_range(0..3u8)));
+                v
+            });
+            assert_eq!(BytesComparator.cmp(&a, &b), a.cmp(&b), "{a:?} {b:?}");
+        }
+    }
+
+    #[test]
+    fn test_natural_merge() {
+        let t = Tournament::from_iters(
+            [vec!["file1", "file10", "file100"], vec!["file2", "file20"]].map(Vec::into_iter),
+            NaturalComparator,
+        );
+        assert_eq!(
+            t.collect::<Vec<_>>(),
+            ["file1", "file2", "file10", "file20", "file100"]
+        );
+    }
+
+    #[test]
+    fn test_dyn_comparator() {
+        let min = MinComparator::default();
+        let comparator: &dyn Comparator<i32> = &min;
+        let t = Tournament::from_iters([0..2, 1..3], comparator);
+        assert_eq!(t.collect::<Vec<_>>(), [0, 1, 1, 2]);
+
+        let comparator: Box<dyn Comparator<i32>> = Box::new(MaxComparator::default());
+        let t = Tournament::from_iters([(0..2).rev(), (1..3).rev()], comparator);
+        assert_eq!(t.collect::<Vec<_>>(), [2, 1, 1, 0]);
+    }
+
+    #[test]
+    fn test_arc_comparator() {
+        static CLONES: AtomicUsize = AtomicUsize::new(0);
+
+        struct Collation(Vec<u8>);
+
+        impl Clone for Collation {
+            fn clone(&self) -> Self {
+                CLONES.fetch_add(1, AtomicOrdering::Relaxed);
+                Collation(self.0.clone())
+            }
+        }
+
+        impl Comparator<u8> for Collation {
+            fn cmp(&self, a: &u8, b: &u8) -> Ordering {
+                self.0[*a as usize].cmp(&self.0[*b as usize])
+            }
+        }
+
+        let comparator = Arc::new(Collation((0..=255).collect()));
+        let t = Tournament::from_iters([[1u8, 4].into_iter(), [2, 3].into_iter()], comparator);
+        let forked = t.clone();
+
+        assert_eq!(t.collect::<Vec<_>>(), [1, 2, 3, 4]);
+        assert_eq!(forked.collect::<Vec<_>>(), [1, 2, 3, 4]);
+        assert_eq!(CLONES.load(AtomicOrdering::Relaxed), 0);
+    }
+
+    #[test]
+    fn test_map_input() {
+        #[derive(Clone, Copy, Debug, PartialEq)]
+        struct Timestamp(i64);
+
+        let comparator = MinComparator::<i64>::default().map_input(|t: &Timestamp| &t.0);
+        assert_eq!(
+            comparator.cmp(&Timestamp(-5), &Timestamp(3)),
+            Ordering::Less
+        );
+        assert_eq!(
+            comparator.cmp(&Timestamp(3), &Timestamp(3)),
+            Ordering::Equal
+        );
+
+        let t = Tournament::from_iters(
+            [vec![Timestamp(-5), Timestamp(7)], vec![Timestamp(0)]].map(Vec::into_iter),
+            comparator,
+        );
+        assert_eq!(
+            t.collect::<Vec<_>>(),
+            [Timestamp(-5), Timestamp(0), Timestamp(7)]
+        );
+    }
+
+    #[test]
+    fn test_option_comparator() {
+        let sources = [
+            vec![None, Some(5), Some(3), None, Some(1)],
+            vec![Some(4), None, Some(2)],
+            vec![],
+        ];
+
+        let mut none_first = sources.clone();
+        let comparator = OptionComparator::new(MaxComparator::default(), true);
+        for source in &mut none_first {
+            source.sort_by(|a, b| comparator.cmp(a, b));
+        }
+        let t = Tournament::from_iters(none_first.map(Vec::into_iter), comparator);
+        assert_eq!(
+            t.collect::<Vec<_>>(),
+            [
+                None,
+                None,
+                None,
+                Some(5),
+                Some(4),
+                Some(3),
+                Some(2),
+                Some(1)
+            ]
+        );
+
+        let mut none_last = sources;
+        let comparator = OptionComparator::new(MaxComparator::default(), false);
+        for source in &mut none_last {
+            source.sort_by(|a, b| comparator.cmp(a, b));
+        }
+        let t = Tournament::from_iters(none_last.map(Vec::into_iter), comparator);
+        assert_eq!(
+            t.collect::<Vec<_>>(),
+            [
+                Some(5),
+                Some(4),
+                Some(3),
+                Some(2),
+                Some(1),
+                None,
+                None,
+                None
+            ]
+        );
+    }
+
+    #[test]
+    fn test_counting_comparator() {
+        let (k, len) = (8, 100);
+        let comparator = CountingComparator::new(MinComparator::default());
+        let counter = comparator.counter();
+
+        let t =
+            Tournament::from_iters((0..k).map(|i| (0..len).map(move |j| j * k + i)), comparator);
+        assert_eq!(t.count(), k * len);
+
+        // Building the heap pushes every source, at up to one comparison per
+        // level, and every merged element is then sifted down or popped, at up to
+        // two comparisons per level: N log k with a factor of two, plus the setup
+        let n = k * len;
+        let levels = k.ilog2() as usize;
+        let mut bound = k * levels + n * 2 * levels;
+        // Debug builds also check every element that follows another one of its
+        // source against it, to catch unsorted sources
+        if cfg!(debug_assertions) {
+            bound += n - k;
+        }
+        let count = counter.load(AtomicOrdering::Relaxed);
+        assert!(count > 0);
+        assert!(
+            count <= bound,
+            "{count} comparisons, expected at most {bound}"
+        );
+    }
+
+    #[test]
+    fn test_ptr_short_circuit() {
+        let counting = CountingComparator::new(MinComparator::<u64>::default());
+        let comparator = PtrShortCircuitComparator::new(counting.clone());
+
+        let a = Arc::new(1);
+        let shared = Arc::clone(&a);
+        assert_eq!(comparator.cmp(&a, &shared), Ordering::Equal);
+        assert_eq!(counting.count(), 0);
+
+        // Equal values in different allocations are compared
+        let other = Arc::new(1);
+        assert_eq!(comparator.cmp(&a, &other), Ordering::Equal);
+        assert_eq!(comparator.cmp(&other, &Arc::new(2)), Ordering::Less);
+        assert_eq!(counting.count(), 2);
+
+        // A merge of sources that all yield the same allocation compares no values
+        let shared = Rc::new(7);
+        let t = Tournament::from_iters(
+            (0..4).map(|_| vec![Rc::clone(&shared); 10].into_iter()),
+            comparator,
+        );
+        assert_eq!(t.map(|item| *item).collect::<Vec<u64>>(), [7; 40]);
+        assert_eq!(counting.count(), 2);
+    }
+
+    #[test]
+    fn test_approx_comparator() {
+        let comparator = ApproxComparator::new(0.01);
+        assert_eq!(comparator.cmp(&1.0, &1.005), Ordering::Equal);
+        assert_eq!(comparator.cmp(&1.0, &1.02), Ordering::Less);
+        assert_eq!(comparator.cmp(&f64::NAN, &1.0), Ordering::Greater);
+
+        // Readings of the same values from sensors with some noise
+        let sensors = [
+            vec![1.0, 2.0, 3.001, 5.0],
+            vec![1.002, 2.999, 4.0],
+            vec![0.999, 2.003, 5.004],
+        ];
+        let t = Tournament::from_iters(sensors.map(Vec::into_iter), comparator);
+        let merged = t.union().collect::<Vec<_>>();
+        assert_eq!(merged.len(), 5);
+        for (value, expected) in merged.into_iter().zip([1.0, 2.0, 3.0, 4.0, 5.0]) {
+            assert!((value - expected).abs() < 0.01, "{value} is not {expected}");
+        }
+    }
+}
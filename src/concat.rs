@@ -0,0 +1,116 @@
+use crate::comparator::Comparator;
+
+/// An iterator over sources that do not overlap, yielding all the elements of
+/// every source before those of the next, without a heap.
+///
+/// Created by
+/// [`Tournament::from_sorted_nonoverlapping`](crate::Tournament::from_sorted_nonoverlapping).
+#[derive(Clone, Debug)]
+pub struct Concatenated<T, C>
+where
+    T: Iterator,
+{
+    sources: Vec<T>,
+    // The source the next element is pulled from
+    current: usize,
+    // The element yielded next, pulled ahead to check the order against
+    head: Option<T::Item>,
+    comparator: C,
+}
+
+impl<T, C> Concatenated<T, C>
+where
+    T: Iterator,
+{
+    pub(crate) fn new<I: IntoIterator<Item = T>>(iters: I, comparator: C) -> Self {
+        let mut concatenated = Concatenated {
+            sources: iters.into_iter().collect(),
+            current: 0,
+            head: None,
+            comparator,
+        };
+        concatenated.head = concatenated.pull();
+        concatenated
+    }
+
+    fn pull(&mut self) -> Option<T::Item> {
+        while let Some(source) = self.sources.get_mut(self.current) {
+            if let Some(item) = source.next() {
+                return Some(item);
+            }
+            self.current += 1;
+        }
+        None
+    }
+}
+
+impl<T, C> Iterator for Concatenated<T, C>
+where
+    T: Iterator,
+    C: Comparator<T::Item>,
+{
+    type Item = T::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.head.take()?;
+        self.head = self.pull();
+        if let Some(next) = &self.head {
+            debug_assert!(
+                self.comparator.cmp(&item, next).is_le(),
+                "source {} overlaps the previous ones, or is not sorted according to the comparator",
+                self.current
+            );
+        }
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let head = self.head.is_some() as usize;
+        self.sources[self.current.min(self.sources.len())..]
+            .iter()
+            .map(Iterator::size_hint)
+            .fold((head, Some(head)), |(lower, upper), (l, u)| {
+                (
+                    lower.saturating_add(l),
+                    upper.zip(u).and_then(|(a, b)| a.checked_add(b)),
+                )
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::Rng;
+
+    use crate::{MinComparator, Tournament};
+
+    #[test]
+    fn test_nonoverlapping_matches_heap() {
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..100 {
+            // Shards of a sorted range, some of them empty
+            let mut bounds = (0..rng.gen_range(0..10))
+                .map(|_| rng.gen_range(0..200))
+                .collect::<Vec<u32>>();
+            bounds.push(0);
+            bounds.push(200);
+            bounds.sort();
+            let sources = bounds.windows(2).map(|w| w[0]..w[1]).collect::<Vec<_>>();
+
+            let heap = Tournament::from_iters_min(sources.clone()).collect::<Vec<_>>();
+            let concatenated =
+                Tournament::from_sorted_nonoverlapping(sources.clone(), MinComparator::default());
+            assert_eq!(concatenated.size_hint(), (200, Some(200)));
+            assert_eq!(concatenated.collect::<Vec<_>>(), heap);
+        }
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "source 1 overlaps")]
+    fn test_overlapping_sources() {
+        let t = Tournament::from_sorted_nonoverlapping([(0..5), (3..8)], MinComparator::default());
+        t.for_each(drop);
+    }
+}
@@ -0,0 +1,198 @@
+use std::cmp::Ordering;
+
+use crate::storage::{self, Storage};
+
+/// A `D`-ary heap that does not rely on [`Ord`] being implemented by its elements.
+/// Instead every operation that may reorder the heap is given a comparison function,
+/// which allows a single comparator to be shared by all the elements. The element
+/// that compares as [`Ordering::Less`] than all the others is at the top.
+///
+/// A higher arity makes the heap shallower, so fewer levels are visited when
+/// sifting down, at the cost of more comparisons per level.
+#[derive(Clone, Debug)]
+pub(crate) struct DaryHeap<E, const D: usize> {
+    data: Storage<E>,
+}
+
+impl<E, const D: usize> DaryHeap<E, D> {
+    pub(crate) fn with_capacity(capacity: usize) -> Self {
+        assert!(D >= 2, "the arity of the heap must be at least 2");
+        DaryHeap {
+            data: Storage::with_capacity(capacity),
+        }
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    pub(crate) fn capacity(&self) -> usize {
+        self.data.capacity()
+    }
+
+    pub(crate) fn reserve(&mut self, additional: usize) {
+        self.data.reserve(additional);
+    }
+
+    pub(crate) fn peek(&self) -> Option<&E> {
+        self.data.first()
+    }
+
+    /// Remove all the elements, keeping the allocated capacity.
+    pub(crate) fn clear(&mut self) {
+        self.data.clear();
+    }
+
+    /// Take all the elements of the heap, in arbitrary order.
+    pub(crate) fn into_vec(self) -> Vec<E> {
+        storage::into_vec(self.data)
+    }
+
+    /// Iterate over the elements of the heap in arbitrary order.
+    pub(crate) fn iter(&self) -> std::slice::Iter<'_, E> {
+        self.data.iter()
+    }
+
+    /// The position of the child of the top element that compares least, which is
+    /// the element that replaces the top when it is popped. It stays in place for
+    /// as long as only the top element is modified, and keeps comparing less.
+    /// Among equal children it is the last one, the same child that
+    /// [`DaryHeap::sift_down_top`] moves up.
+    pub(crate) fn runner_up<F>(&self, cmp: F) -> Option<usize>
+    where
+        F: Fn(&E, &E) -> Ordering,
+    {
+        (1..(D + 1).min(self.data.len())).reduce(|best, pos| {
+            match cmp(&self.data[pos], &self.data[best]) {
+                Ordering::Greater => best,
+                _ => pos,
+            }
+        })
+    }
+
+    /// Replace the top element with `element`, returning the previous top. If
+    /// `element` compares less than the element at `runner_up`, as given by
+    /// [`DaryHeap::runner_up`], the order holds without sifting, and `true` is
+    /// returned alongside.
+    pub(crate) fn replace_top<F>(&mut self, element: E, runner_up: usize, cmp: F) -> (E, bool)
+    where
+        F: Fn(&E, &E) -> Ordering,
+    {
+        let before = cmp(&element, &self.data[runner_up]).is_lt();
+        let top = std::mem::replace(&mut self.data[0], element);
+        if !before {
+            // The runner up is the least child, so it moves up without comparing
+            // the children again, and the element sinks on from its place
+            self.data.swap(0, runner_up);
+            self.sift_down(runner_up, |a, b| cmp(a, b).then(Ordering::Less));
+        }
+        (top, before)
+    }
+
+    /// Mutable access to the top element. If the element is modified in a way that
+    /// changes its order, [`DaryHeap::sift_down_top`] must be called afterwards.
+    pub(crate) fn peek_mut(&mut self) -> Option<&mut E> {
+        self.data.first_mut()
+    }
+
+    /// Restore the order of the heap after the top element was modified. The
+    /// modified element is placed after any elements it draws with, just like it
+    /// would if it was popped and pushed back.
+    pub(crate) fn sift_down_top<F>(&mut self, cmp: F)
+    where
+        F: Fn(&E, &E) -> Ordering,
+    {
+        self.sift_down(0, |a, b| cmp(a, b).then(Ordering::Less));
+    }
+
+    pub(crate) fn push<F>(&mut self, element: E, cmp: F)
+    where
+        F: Fn(&E, &E) -> Ordering,
+    {
+        self.data.push(element);
+        self.sift_up(self.data.len() - 1, cmp);
+    }
+
+    pub(crate) fn pop<F>(&mut self, cmp: F) -> Option<E>
+    where
+        F: Fn(&E, &E) -> Ordering,
+    {
+        let last = self.data.pop()?;
+        if self.data.is_empty() {
+            return Some(last);
+        }
+
+        let top = std::mem::replace(&mut self.data[0], last);
+        self.sift_down(0, cmp);
+        Some(top)
+    }
+
+    /// Remove the element at position `pos`, as given by the order of [`DaryHeap::iter`].
+    pub(crate) fn remove<F>(&mut self, pos: usize, cmp: F) -> E
+    where
+        F: Fn(&E, &E) -> Ordering,
+    {
+        let element = self.data.swap_remove(pos);
+        if pos < self.data.len() {
+            self.sift_down(pos, &cmp);
+            self.sift_up(pos, &cmp);
+        }
+        element
+    }
+
+    fn sift_up<F>(&mut self, pos: usize, cmp: F)
+    where
+        F: Fn(&E, &E) -> Ordering,
+    {
+        sift_up::<E, F, D>(&mut self.data, pos, cmp);
+    }
+
+    fn sift_down<F>(&mut self, pos: usize, cmp: F)
+    where
+        F: Fn(&E, &E) -> Ordering,
+    {
+        sift_down::<E, F, D>(&mut self.data, pos, cmp);
+    }
+}
+
+/// Move the element at `pos` up a `D`-ary heap stored in `data`, until its parent
+/// does not compare greater. Shared with heaps that do not own a [`Vec`].
+pub(crate) fn sift_up<E, F, const D: usize>(data: &mut [E], mut pos: usize, cmp: F)
+where
+    F: Fn(&E, &E) -> Ordering,
+{
+    while pos > 0 {
+        let parent = (pos - 1) / D;
+        if cmp(&data[pos], &data[parent]).is_ge() {
+            break;
+        }
+        data.swap(pos, parent);
+        pos = parent;
+    }
+}
+
+/// Move the element at `pos` down a `D`-ary heap stored in `data`, until none of
+/// its children compares less.
+pub(crate) fn sift_down<E, F, const D: usize>(data: &mut [E], mut pos: usize, cmp: F)
+where
+    F: Fn(&E, &E) -> Ordering,
+{
+    let len = data.len();
+    loop {
+        let first = D * pos + 1;
+        if first >= len {
+            break;
+        }
+        let mut child = first;
+        for next in first + 1..(first + D).min(len) {
+            if cmp(&data[next], &data[child]).is_lt() {
+                child = next;
+            }
+        }
+        if cmp(&data[child], &data[pos]).is_ge() {
+            break;
+        }
+        data.swap(pos, child);
+        pos = child;
+    }
+}
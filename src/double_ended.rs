@@ -0,0 +1,463 @@
+use std::cmp::Ordering;
+
+use crate::comparator::Comparator;
+use crate::dary_heap::DaryHeap;
+use crate::tie_break::TieBreak;
+
+/// The state of a [`Tournament`](crate::Tournament) that is consumed from both ends.
+///
+/// Every source slot buffers up to two elements: its head, pulled with `next`, and
+/// its tail, pulled with `next_back`. When a single element is left in a slot it has
+/// to be visible from both ends, so instead of holding the elements the heaps hold
+/// slot indices, and order them by the smallest or largest element of the slot.
+#[derive(Clone, Debug)]
+pub(crate) struct DoubleEnded<I, const D: usize> {
+    heads: Vec<Option<I>>,
+    tails: Vec<Option<I>>,
+    // Every slot with elements left, ordered by its smallest element
+    front: DaryHeap<usize, D>,
+    // Every slot with elements left, ordered by its largest element, except pending ones
+    back: DaryHeap<usize, D>,
+    // Slots whose tail was not pulled yet, which is done on the next call to `next_back`
+    pending: Vec<usize>,
+}
+
+impl<I, const D: usize> DoubleEnded<I, D> {
+    /// Start tracking both ends of `slots` sources, given the heads of the active ones.
+    pub(crate) fn new<H, C>(heads: H, slots: usize, comparator: &C, tie_break: &TieBreak) -> Self
+    where
+        H: IntoIterator<Item = (usize, I)>,
+        C: Comparator<I>,
+    {
+        let mut double_ended = DoubleEnded {
+            heads: std::iter::repeat_with(|| None).take(slots).collect(),
+            tails: std::iter::repeat_with(|| None).take(slots).collect(),
+            front: DaryHeap::with_capacity(slots),
+            back: DaryHeap::with_capacity(slots),
+            pending: Vec::with_capacity(slots),
+        };
+        for (slot, head) in heads {
+            double_ended.push(slot, head, comparator, tie_break);
+        }
+        double_ended
+    }
+
+    /// Convert to a `E`-ary heap, as with [`Tournament::with_arity`](crate::Tournament::with_arity).
+    pub(crate) fn with_arity<const E: usize, C>(
+        self,
+        comparator: &C,
+        tie_break: &TieBreak,
+    ) -> DoubleEnded<I, E>
+    where
+        C: Comparator<I>,
+    {
+        let DoubleEnded {
+            heads,
+            tails,
+            front,
+            back,
+            pending,
+        } = self;
+
+        let mut new_front = DaryHeap::with_capacity(front.len());
+        for slot in front.into_vec() {
+            new_front.push(slot, front_order(&heads, &tails, comparator, tie_break));
+        }
+        let mut new_back = DaryHeap::with_capacity(back.len());
+        for slot in back.into_vec() {
+            new_back.push(slot, back_order(&heads, &tails, comparator, tie_break));
+        }
+
+        DoubleEnded {
+            heads,
+            tails,
+            front: new_front,
+            back: new_back,
+            pending,
+        }
+    }
+
+    /// Add the head of a new source slot.
+    pub(crate) fn push<C>(&mut self, slot: usize, head: I, comparator: &C, tie_break: &TieBreak)
+    where
+        C: Comparator<I>,
+    {
+        if slot >= self.heads.len() {
+            self.heads.resize_with(slot + 1, || None);
+            self.tails.resize_with(slot + 1, || None);
+        }
+        self.heads[slot] = Some(head);
+        self.front.push(
+            slot,
+            front_order(&self.heads, &self.tails, comparator, tie_break),
+        );
+        self.pending.push(slot);
+    }
+
+    /// Take over the slots of another tournament, whose slot `i` becomes slot
+    /// `offset + i`.
+    pub(crate) fn absorb<const E: usize, C>(
+        &mut self,
+        other: DoubleEnded<I, E>,
+        offset: usize,
+        comparator: &C,
+        tie_break: &TieBreak,
+    ) where
+        C: Comparator<I>,
+    {
+        let DoubleEnded {
+            mut heads,
+            mut tails,
+            front,
+            pending,
+            ..
+        } = other;
+
+        let slots = offset + heads.len();
+        self.heads.resize_with(slots, || None);
+        self.tails.resize_with(slots, || None);
+
+        for slot in front.into_vec() {
+            self.heads[offset + slot] = heads[slot].take();
+            self.tails[offset + slot] = tails[slot].take();
+            self.front.push(
+                offset + slot,
+                front_order(&self.heads, &self.tails, comparator, tie_break),
+            );
+            if pending.contains(&slot) {
+                self.pending.push(offset + slot);
+            } else {
+                self.back.push(
+                    offset + slot,
+                    back_order(&self.heads, &self.tails, comparator, tie_break),
+                );
+            }
+        }
+    }
+
+    /// The number of slots with elements left.
+    pub(crate) fn len(&self) -> usize {
+        self.front.len()
+    }
+
+    /// The number of slots the front heap has room for without reallocating.
+    pub(crate) fn capacity(&self) -> usize {
+        self.front.capacity()
+    }
+
+    /// Bounds on the number of elements left, buffered or not.
+    pub(crate) fn size_hint<T>(&self, results: &[T]) -> (usize, Option<usize>)
+    where
+        T: Iterator<Item = I>,
+    {
+        self.front
+            .iter()
+            .fold((0, Some(0)), |(lower, upper), &slot| {
+                let buffered =
+                    self.heads[slot].is_some() as usize + self.tails[slot].is_some() as usize;
+                let (l, u) = results[slot].size_hint();
+                (
+                    lower.saturating_add(l).saturating_add(buffered),
+                    upper
+                        .zip(u)
+                        .and_then(|(a, b)| a.checked_add(b))
+                        .and_then(|u| u.checked_add(buffered)),
+                )
+            })
+    }
+
+    /// The smallest element left, which the next call to `next` will return.
+    pub(crate) fn peek(&self) -> Option<&I> {
+        self.front
+            .peek()
+            .map(|&slot| smallest(&self.heads, &self.tails, slot))
+    }
+
+    /// The smallest element buffered by another slot than the one of
+    /// [`DoubleEnded::peek`].
+    pub(crate) fn runner_up<C>(&self, comparator: &C, tie_break: &TieBreak) -> Option<&I>
+    where
+        C: Comparator<I>,
+    {
+        let order = front_order(&self.heads, &self.tails, comparator, tie_break);
+        let pos = self.front.runner_up(order)?;
+        let slot = *self.front.iter().nth(pos)?;
+        Some(smallest(&self.heads, &self.tails, slot))
+    }
+
+    /// Yield the smallest element left, together with its slot, and whether the
+    /// slot's source stayed sorted. If `check` is set, a source that yields an
+    /// element ordered before the previous one is dropped, rather than asserted on.
+    pub(crate) fn next<T, C>(
+        &mut self,
+        results: &mut [T],
+        comparator: &C,
+        tie_break: &TieBreak,
+        check: bool,
+    ) -> Option<(usize, I, bool)>
+    where
+        T: Iterator<Item = I>,
+        C: Comparator<I>,
+    {
+        let DoubleEnded {
+            heads,
+            tails,
+            front,
+            back,
+            pending,
+        } = self;
+        let slot = *front.peek()?;
+
+        let mut sorted = true;
+        let item = match heads[slot].take() {
+            Some(item) => {
+                heads[slot] = results[slot].next();
+                if check {
+                    // Once the source is drained, its tail is the next element
+                    if let Some(next) = heads[slot].as_ref().or(tails[slot].as_ref()) {
+                        sorted = comparator.cmp(next, &item).is_ge();
+                    }
+                } else if let Some(next) = &heads[slot] {
+                    debug_assert!(
+                        comparator.cmp(next, &item).is_ge(),
+                        "source {slot} is not sorted according to the comparator"
+                    );
+                }
+                if !sorted {
+                    heads[slot] = None;
+                    tails[slot] = None;
+                }
+                item
+            }
+            // The only element left was pulled from the back
+            None => tails[slot].take()?,
+        };
+
+        if heads[slot].is_some() || tails[slot].is_some() {
+            front.sift_down_top(front_order(heads, tails, comparator, tie_break));
+        } else {
+            // The slot is exhausted, so it has to leave the back as well
+            front.pop(front_order(heads, tails, comparator, tie_break));
+            match pending.iter().position(|&s| s == slot) {
+                Some(pos) => {
+                    pending.swap_remove(pos);
+                }
+                None => {
+                    if let Some(pos) = back.iter().position(|&s| s == slot) {
+                        back.remove(pos, back_order(heads, tails, comparator, tie_break));
+                    }
+                }
+            }
+        }
+
+        Some((slot, item, sorted))
+    }
+
+    /// Yield the largest element left, together with its slot.
+    pub(crate) fn next_back<T, C>(
+        &mut self,
+        results: &mut [T],
+        comparator: &C,
+        tie_break: &TieBreak,
+    ) -> Option<(usize, I)>
+    where
+        T: DoubleEndedIterator<Item = I>,
+        C: Comparator<I>,
+    {
+        let DoubleEnded {
+            heads,
+            tails,
+            front,
+            back,
+            pending,
+        } = self;
+
+        for slot in pending.drain(..) {
+            tails[slot] = results[slot].next_back();
+            if let (Some(head), Some(tail)) = (&heads[slot], &tails[slot]) {
+                debug_assert!(
+                    comparator.cmp(tail, head).is_ge(),
+                    "source {slot} is not sorted according to the comparator"
+                );
+            }
+            back.push(slot, back_order(heads, tails, comparator, tie_break));
+        }
+
+        let slot = *back.peek()?;
+
+        let item = match tails[slot].take() {
+            Some(item) => {
+                tails[slot] = results[slot].next_back();
+                if let Some(next) = &tails[slot] {
+                    debug_assert!(
+                        comparator.cmp(next, &item).is_le(),
+                        "source {slot} is not sorted according to the comparator"
+                    );
+                }
+                item
+            }
+            // The only element left was pulled from the front
+            None => heads[slot].take()?,
+        };
+
+        if heads[slot].is_some() || tails[slot].is_some() {
+            back.sift_down_top(back_order(heads, tails, comparator, tie_break));
+        } else {
+            // The slot is exhausted, so it has to leave the front as well
+            back.pop(back_order(heads, tails, comparator, tie_break));
+            if let Some(pos) = front.iter().position(|&s| s == slot) {
+                front.remove(pos, front_order(heads, tails, comparator, tie_break));
+            }
+        }
+
+        Some((slot, item))
+    }
+
+    /// The largest element left, draining the sources whose tail was not pulled yet.
+    pub(crate) fn last<T, C>(
+        self,
+        results: impl IntoIterator<Item = T>,
+        comparator: &C,
+        tie_break: &TieBreak,
+    ) -> Option<I>
+    where
+        T: Iterator<Item = I>,
+        C: Comparator<I>,
+    {
+        let DoubleEnded {
+            mut heads,
+            mut tails,
+            back,
+            pending,
+            ..
+        } = self;
+        let mut results = results.into_iter().map(Some).collect::<Vec<_>>();
+
+        let back_last = back.peek().and_then(|&slot| {
+            let item = tails[slot].take().or_else(|| heads[slot].take())?;
+            Some((slot, item))
+        });
+
+        pending
+            .into_iter()
+            .filter_map(|slot| {
+                let item = results[slot]
+                    .take()
+                    .and_then(Iterator::last)
+                    .or_else(|| heads[slot].take())?;
+                Some((slot, item))
+            })
+            .chain(back_last)
+            .max_by(|(a_slot, a), (b_slot, b)| {
+                comparator
+                    .cmp(a, b)
+                    .then_with(|| tie_break.cmp(*a_slot, *b_slot))
+            })
+            .map(|(_, item)| item)
+    }
+
+    /// The active slots, each with its smallest element left.
+    pub(crate) fn active(&self) -> impl Iterator<Item = (usize, &I)> + '_ {
+        self.front
+            .iter()
+            .map(|&slot| (slot, smallest(&self.heads, &self.tails, slot)))
+    }
+
+    /// Renumber the slots, moving slot `i` to `remap[i]`, and dropping the elements
+    /// of the slots that map to `None`. The order of the kept slots must not change.
+    pub(crate) fn retain<C>(
+        &mut self,
+        remap: &[Option<usize>],
+        comparator: &C,
+        tie_break: &TieBreak,
+    ) where
+        C: Comparator<I>,
+    {
+        let slots = remap.iter().flatten().count();
+        let mut heads = std::iter::repeat_with(|| None)
+            .take(slots)
+            .collect::<Vec<_>>();
+        let mut tails = std::iter::repeat_with(|| None)
+            .take(slots)
+            .collect::<Vec<_>>();
+        for (slot, &new) in remap.iter().enumerate() {
+            if let Some(new) = new {
+                heads[new] = self.heads[slot].take();
+                tails[new] = self.tails[slot].take();
+            }
+        }
+
+        let mut front = DaryHeap::with_capacity(self.front.len());
+        for slot in self.front.iter().filter_map(|&slot| remap[slot]) {
+            front.push(slot, front_order(&heads, &tails, comparator, tie_break));
+        }
+        let mut back = DaryHeap::with_capacity(self.back.len());
+        for slot in self.back.iter().filter_map(|&slot| remap[slot]) {
+            back.push(slot, back_order(&heads, &tails, comparator, tie_break));
+        }
+        self.pending = self
+            .pending
+            .iter()
+            .filter_map(|&slot| remap[slot])
+            .collect();
+
+        self.heads = heads;
+        self.tails = tails;
+        self.front = front;
+        self.back = back;
+    }
+
+    /// Take the heads and the tails of the slots.
+    pub(crate) fn into_ends(self) -> Vec<(Option<I>, Option<I>)> {
+        self.heads.into_iter().zip(self.tails).collect()
+    }
+}
+
+fn smallest<'a, I>(heads: &'a [Option<I>], tails: &'a [Option<I>], slot: usize) -> &'a I {
+    heads[slot]
+        .as_ref()
+        .or(tails[slot].as_ref())
+        .expect("an active slot buffers at least one element")
+}
+
+fn largest<'a, I>(heads: &'a [Option<I>], tails: &'a [Option<I>], slot: usize) -> &'a I {
+    tails[slot]
+        .as_ref()
+        .or(heads[slot].as_ref())
+        .expect("an active slot buffers at least one element")
+}
+
+/// The order of the slots in the front heap, smallest element first.
+fn front_order<'a, I, C>(
+    heads: &'a [Option<I>],
+    tails: &'a [Option<I>],
+    comparator: &'a C,
+    tie_break: &'a TieBreak,
+) -> impl Fn(&usize, &usize) -> Ordering + 'a
+where
+    C: Comparator<I>,
+{
+    move |&a, &b| {
+        comparator
+            .cmp(smallest(heads, tails, a), smallest(heads, tails, b))
+            .then_with(|| tie_break.cmp(a, b))
+    }
+}
+
+/// The order of the slots in the back heap, largest element first, and the slots
+/// that win a draw from the front last.
+fn back_order<'a, I, C>(
+    heads: &'a [Option<I>],
+    tails: &'a [Option<I>],
+    comparator: &'a C,
+    tie_break: &'a TieBreak,
+) -> impl Fn(&usize, &usize) -> Ordering + 'a
+where
+    C: Comparator<I>,
+{
+    move |&a, &b| {
+        comparator
+            .cmp(largest(heads, tails, b), largest(heads, tails, a))
+            .then_with(|| tie_break.cmp(b, a))
+    }
+}
@@ -0,0 +1,41 @@
+use std::{error::Error, fmt};
+
+/// The error returned when a source does not yield its elements in the order
+/// defined by the comparator of the tournament.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct UnsortedError {
+    source_index: usize,
+    position: usize,
+}
+
+impl UnsortedError {
+    pub(crate) fn new(source_index: usize, position: usize) -> Self {
+        UnsortedError {
+            source_index,
+            position,
+        }
+    }
+
+    /// The index of the offending source.
+    pub fn source_index(&self) -> usize {
+        self.source_index
+    }
+
+    /// The position within the source of the first element that is ordered
+    /// before its predecessor.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+}
+
+impl fmt::Display for UnsortedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "source {} is not sorted: element at position {} is ordered before its predecessor",
+            self.source_index, self.position
+        )
+    }
+}
+
+impl Error for UnsortedError {}
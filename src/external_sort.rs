@@ -0,0 +1,261 @@
+//! Merging of sorted runs that are too large to fit in memory, the last step of an
+//! external merge sort, and of sorted text streams in general.
+
+use std::cell::RefCell;
+use std::cmp::Ordering;
+use std::io::{self, BufRead, Write};
+use std::rc::Rc;
+
+use streaming_iterator::StreamingIterator;
+
+use crate::comparator::Comparator;
+use crate::{StreamingTournament, Tournament};
+
+/// Merge runs of lines, each of which is sorted by the key `parse` extracts from
+/// a line, into `out`. Only the current line of every run is kept in memory.
+///
+/// The lines are written as they were read, one per line, with `\n` endings. The
+/// line ending is not part of the line given to `parse`, whether it is `\n` or
+/// `\r\n`, and a run whose last line has no ending is handled like one that does.
+/// Empty runs are skipped. The first IO error stops the merge and is returned.
+///
+/// # Examples
+///
+/// ```
+/// use tournament_kway::{external_sort, MinComparator};
+///
+/// let runs = ["1,a\n3,c\n", "", "2,b\n10,j"].map(str::as_bytes);
+/// let mut out = Vec::new();
+/// external_sort::merge_runs(
+///     runs,
+///     |line| line.split(',').next().unwrap().parse::<u32>().unwrap(),
+///     MinComparator::default(),
+///     &mut out,
+/// )
+/// .unwrap();
+/// assert_eq!(String::from_utf8(out).unwrap(), "1,a\n2,b\n3,c\n10,j\n");
+/// ```
+pub fn merge_runs<R, I, K, P, C, W>(
+    readers: I,
+    parse: P,
+    comparator: C,
+    out: &mut W,
+) -> io::Result<()>
+where
+    R: BufRead,
+    I: IntoIterator<Item = R>,
+    P: Fn(&str) -> K,
+    C: Comparator<K>,
+    W: Write,
+{
+    let error = Rc::new(RefCell::new(None));
+    let runs = readers.into_iter().map(|reader| Run {
+        reader,
+        parse: &parse,
+        current: None,
+        error: Rc::clone(&error),
+    });
+
+    let mut tournament = StreamingTournament::from_iters(runs, ByKey(comparator));
+    loop {
+        tournament.advance();
+        if let Some(err) = error.borrow_mut().take() {
+            return Err(err);
+        }
+        match tournament.get() {
+            Some(line) => writeln!(out, "{}", line.text)?,
+            None => return Ok(()),
+        }
+    }
+}
+
+/// Merge lines from readers whose lines are each sorted lexically, as `sort -m`
+/// does. The lines are yielded without their `\n` or `\r\n` endings.
+///
+/// If a reader fails, the error is yielded in place of the next line, after which
+/// the merge ends.
+///
+/// # Examples
+///
+/// ```
+/// use tournament_kway::external_sort;
+///
+/// let merged = external_sort::merge_lines(vec!["apple\ncherry\n".as_bytes(), "banana\n".as_bytes()])
+///     .collect::<std::io::Result<Vec<_>>>()
+///     .unwrap();
+/// assert_eq!(merged, ["apple", "banana", "cherry"]);
+/// ```
+pub fn merge_lines<R: BufRead>(readers: Vec<R>) -> impl Iterator<Item = io::Result<String>> {
+    let error = Rc::new(RefCell::new(None));
+    let mut tournament = Tournament::from_iters_min(readers.into_iter().map(|reader| Lines {
+        lines: reader.lines(),
+        error: Rc::clone(&error),
+    }));
+
+    let mut failed = false;
+    std::iter::from_fn(move || {
+        if failed {
+            return None;
+        }
+        // The error of a reader is found when it is advanced, after its previous
+        // line was merged, so it is reported on the following call
+        if let Some(err) = error.borrow_mut().take() {
+            failed = true;
+            return Some(Err(err));
+        }
+        tournament.next().map(Ok)
+    })
+}
+
+/// The lines of a reader, ending at the first error, which is reported separately.
+struct Lines<R> {
+    lines: io::Lines<R>,
+    error: Rc<RefCell<Option<io::Error>>>,
+}
+
+impl<R: BufRead> Iterator for Lines<R> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.lines.next()? {
+            Ok(line) => Some(line),
+            Err(err) => {
+                self.error.borrow_mut().get_or_insert(err);
+                None
+            }
+        }
+    }
+}
+
+/// A line of a run, with its key.
+struct Line<K> {
+    key: K,
+    text: String,
+}
+
+/// A sorted run, read one line at a time.
+struct Run<'a, R, P, K> {
+    reader: R,
+    parse: &'a P,
+    current: Option<Line<K>>,
+    // Where the first IO error of any run is reported, ending the run
+    error: Rc<RefCell<Option<io::Error>>>,
+}
+
+impl<R, P, K> StreamingIterator for Run<'_, R, P, K>
+where
+    R: BufRead,
+    P: Fn(&str) -> K,
+{
+    type Item = Line<K>;
+
+    fn advance(&mut self) {
+        // Reuse the buffer of the previous line
+        let mut text = self
+            .current
+            .take()
+            .map(|line| line.text)
+            .unwrap_or_default();
+        text.clear();
+
+        match self.reader.read_line(&mut text) {
+            Ok(0) => {}
+            Ok(_) => {
+                if text.ends_with('\n') {
+                    text.pop();
+                    if text.ends_with('\r') {
+                        text.pop();
+                    }
+                }
+                self.current = Some(Line {
+                    key: (self.parse)(&text),
+                    text,
+                });
+            }
+            Err(err) => {
+                self.error.borrow_mut().get_or_insert(err);
+            }
+        }
+    }
+
+    fn get(&self) -> Option<&Self::Item> {
+        self.current.as_ref()
+    }
+}
+
+/// Orders the lines of the runs by their keys.
+struct ByKey<C>(C);
+
+impl<K, C: Comparator<K>> Comparator<Line<K>> for ByKey<C> {
+    #[inline(always)]
+    fn cmp(&self, a: &Line<K>, b: &Line<K>) -> Ordering {
+        self.0.cmp(&a.key, &b.key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{self, BufRead, BufReader, Read};
+
+    use super::{merge_lines, merge_runs};
+    use crate::{MaxComparator, MinComparator};
+
+    #[test]
+    fn test_merge_runs() {
+        let runs = ["d\r\nb\r\n", "", "e\nc\na\n\n", "f"].map(str::as_bytes);
+        let mut out = Vec::new();
+        merge_runs(runs, str::to_owned, MaxComparator::default(), &mut out).unwrap();
+        assert_eq!(out, b"f\ne\nd\nc\nb\na\n\n");
+
+        let runs = ["\na\nc\n", "", "b\r\nd"].map(str::as_bytes);
+        let mut out = Vec::new();
+        merge_runs(runs, str::to_owned, MinComparator::default(), &mut out).unwrap();
+        assert_eq!(out, b"\na\nb\nc\nd\n");
+    }
+
+    #[test]
+    fn test_merge_runs_error() {
+        struct Failing;
+
+        impl Read for Failing {
+            fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+                Err(io::Error::other("disk on fire"))
+            }
+        }
+
+        let runs: Vec<Box<dyn BufRead>> = vec![
+            Box::new("a\nb\n".as_bytes()),
+            Box::new(BufReader::new(Failing)),
+        ];
+        let mut out = Vec::new();
+        let err = merge_runs(runs, str::to_owned, MinComparator::default(), &mut out).unwrap_err();
+        assert_eq!(err.to_string(), "disk on fire");
+    }
+
+    #[test]
+    fn test_merge_lines() {
+        let merged = merge_lines(vec!["a\r\nc\n".as_bytes(), b"", b"b\nd"])
+            .collect::<io::Result<Vec<_>>>()
+            .unwrap();
+        assert_eq!(merged, ["a", "b", "c", "d"]);
+
+        struct Failing;
+
+        impl Read for Failing {
+            fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+                Err(io::Error::other("disk on fire"))
+            }
+        }
+
+        let readers: Vec<Box<dyn BufRead>> = vec![
+            Box::new("a\nb\n".as_bytes()),
+            Box::new(BufReader::new(Failing)),
+        ];
+        let mut merged = merge_lines(readers);
+        assert_eq!(
+            merged.next().unwrap().unwrap_err().to_string(),
+            "disk on fire"
+        );
+        assert!(merged.next().is_none());
+    }
+}
@@ -0,0 +1,136 @@
+use std::cmp::Ordering;
+
+use crate::{comparator::Comparator, dary_heap::DaryHeap};
+
+/// A tournament over sorted slices, that emits a whole run of elements from the
+/// winning slice at once, for as long as it keeps winning. The length of the run
+/// is found with an exponential search against the runner up, which avoids a heap
+/// operation for every element when one of the sources dominates.
+///
+/// Created by [`Tournament::from_sorted_slices`](crate::Tournament::from_sorted_slices).
+#[derive(Clone, Debug)]
+pub struct GallopingTournament<'a, I, C> {
+    tree: DaryHeap<&'a [I], 2>,
+    // The run of elements that are known to win before any other source
+    run: &'a [I],
+    comparator: C,
+}
+
+impl<'a, I, C> GallopingTournament<'a, I, C>
+where
+    C: Comparator<I>,
+{
+    pub(crate) fn new<S: IntoIterator<Item = &'a [I]>>(slices: S, comparator: C) -> Self {
+        let slices = slices.into_iter();
+        let mut tree = DaryHeap::with_capacity(slices.size_hint().0);
+        for slice in slices.filter(|s| !s.is_empty()) {
+            tree.push(slice, slice_order(&comparator));
+        }
+
+        GallopingTournament {
+            tree,
+            run: &[],
+            comparator,
+        }
+    }
+
+    /// The number of leading elements of `slice` that are not ordered after `bound`.
+    fn gallop(&self, slice: &[I], bound: &I) -> usize {
+        let wins = |item: &I| self.comparator.cmp(item, bound) != Ordering::Greater;
+
+        let mut end = 1;
+        while end < slice.len() && wins(&slice[end]) {
+            end *= 2;
+        }
+        let start = end / 2;
+        let end = end.min(slice.len());
+        start + slice[start..end].partition_point(wins)
+    }
+}
+
+impl<'a, I, C> Iterator for GallopingTournament<'a, I, C>
+where
+    C: Comparator<I>,
+{
+    type Item = &'a I;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.run.is_empty() {
+            let slice = self.tree.pop(slice_order(&self.comparator))?;
+            let len = match self.tree.peek() {
+                None => slice.len(),
+                Some(runner_up) => self.gallop(slice, &runner_up[0]),
+            };
+
+            let (run, rest) = slice.split_at(len);
+            if !rest.is_empty() {
+                self.tree.push(rest, slice_order(&self.comparator));
+            }
+            self.run = run;
+        }
+
+        let (first, rest) = self.run.split_first()?;
+        self.run = rest;
+        Some(first)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.run.len() + self.tree.iter().map(|s| s.len()).sum::<usize>();
+        (len, Some(len))
+    }
+}
+
+impl<I, C> ExactSizeIterator for GallopingTournament<'_, I, C> where C: Comparator<I> {}
+
+/// The order of the slices in the tree, by comparing their first elements
+fn slice_order<I, C>(comparator: &C) -> impl Fn(&&[I], &&[I]) -> Ordering + '_
+where
+    C: Comparator<I>,
+{
+    move |a, b| comparator.cmp(&a[0], &b[0])
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::Rng;
+
+    use crate::{MinComparator, Tournament};
+
+    #[test]
+    fn test_galloping() {
+        let mut rng = rand::thread_rng();
+
+        let vecs = (0..50)
+            .map(|i| {
+                let mut v = (0..rng.gen_range(0..20 * i + 1))
+                    .map(|_| rng.gen_range(0..1000))
+                    .collect::<Vec<u32>>();
+                v.sort();
+                v
+            })
+            .collect::<Vec<_>>();
+
+        let galloping = Tournament::from_sorted_slices(
+            vecs.iter().map(Vec::as_slice),
+            MinComparator::default(),
+        );
+        assert_eq!(galloping.len(), vecs.iter().map(Vec::len).sum::<usize>());
+
+        let mut sort_result = vecs.iter().flatten().collect::<Vec<_>>();
+        sort_result.sort();
+
+        assert_eq!(galloping.collect::<Vec<_>>(), sort_result);
+    }
+
+    #[test]
+    fn test_galloping_skewed() {
+        let big = (0..100_000).collect::<Vec<u32>>();
+        let small = [500, 50_000, 200_000];
+        let galloping =
+            Tournament::from_sorted_slices([big.as_slice(), &small, &[]], MinComparator::default());
+
+        let merged = galloping.copied().collect::<Vec<_>>();
+        assert_eq!(merged.len(), 100_003);
+        assert!(merged.windows(2).all(|w| w[0] <= w[1]));
+    }
+}
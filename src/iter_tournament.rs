@@ -1,16 +1,71 @@
-use std::{cmp::Ordering, collections::BinaryHeap};
+use std::cmp::Ordering;
+use std::ops::ControlFlow;
+use std::sync::Arc;
 
-use crate::comparator::{Comparator, MaxComparator, MinComparator};
+#[cfg(feature = "std")]
+use crate::adapters::TakeUntil;
+use crate::adapters::{
+    Checked, Chunks, Coalesce, DedupKeep, Filter, FilterMap, GroupByKey, InspectSource,
+    LazyTournament, Lending, LimitedTournament, MapMonotonic, PeekableTournament, Ranked, Runs,
+    StepBy, TakeWhileKey, WithStats,
+};
+use crate::array_tournament::ArrayTournament;
+use crate::comparator::{
+    by_key, ByKey, Comparator, DirectionComparator, MaxComparator, MinComparator, RefComparator,
+    TotalF32Comparator, TotalF64Comparator,
+};
+use crate::concat::Concatenated;
+use crate::dary_heap::DaryHeap;
+use crate::double_ended::DoubleEnded;
+use crate::error::UnsortedError;
+use crate::galloping::GallopingTournament;
+use crate::merge2::Merge2;
+use crate::set_ops::{Difference, Intersection, Union};
+use crate::storage::Storage;
+use crate::tie_break::TieBreak;
 
 /// A tournament that implements [`Iterator`] and merges [`Iterator`]s.
+///
+/// The contestants are kept in a `D`-ary heap, binary by default. A higher arity
+/// may perform better when merging a large number of sources, see
+/// [`Tournament::with_arity`].
+///
+/// A tournament holds nothing but its sources, their buffered elements and the
+/// comparator, so it is [`Send`] when `T`, `T::Item` and `C` are all `Send`, and
+/// [`Sync`] when they are all `Sync`. A merge can be moved to a worker thread as
+/// long as its sources can.
+///
+/// [`Iterator::sum`] and [`Iterator::product`] consume the elements in the merged
+/// order, so a merge of floats is added up in the order of the comparator, with the
+/// same rounding as summing the concatenation of the sources sorted by it.
+///
+/// If the comparator panics while the tournament is advanced, the panic leaves the
+/// heap in an unspecified order, and the element being yielded is lost. Using the
+/// tournament after that is memory safe, but its output is no longer sorted. In
+/// debug builds the tournament is poisoned instead, and every later call to `next`
+/// or `next_back` panics, rather than yielding elements out of order.
 #[derive(Clone, Debug)]
-
-pub struct Tournament<T, C>
+pub struct Tournament<T, C, const D: usize = 2>
 where
     T: Iterator,
 {
-    tree: BinaryHeap<TournamentEntry<T::Item, C>>,
-    results: Vec<T>,
+    tree: DaryHeap<TournamentEntry<T::Item>, D>,
+    // The sources that were not empty when added, indexed by the entries of the tree
+    results: Storage<T>,
+    // The index of every slot's source among all the sources added
+    ids: Storage<usize>,
+    // The number of sources added, including empty ones
+    sources: usize,
+    // The number of sources that were empty when added
+    empty: usize,
+    comparator: C,
+    // Decides between sources whose elements draw
+    tie_break: TieBreak,
+    // Set once the tournament is consumed from the back, which takes over the tree
+    double_ended: Option<Box<DoubleEnded<T::Item, D>>>,
+    // Set while the heap is updated in debug builds, so it stays set if the
+    // comparator panics
+    poisoned: bool,
 }
 
 impl<T> Tournament<T, MinComparator<T::Item>>
@@ -26,6 +81,23 @@ where
     ) -> Tournament<T, MinComparator<T::Item>> {
         Tournament::from_iters(iters, MinComparator::default())
     }
+
+    /// A tournament that merges sources sorted in ascending order, from smallest to largest.
+    /// The same as [`Tournament::from_iters_min`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tournament_kway::Tournament;
+    ///
+    /// let t = Tournament::from_iters_ascending([(1..4), (2..5)]);
+    /// assert_eq!(t.collect::<Vec<_>>(), [1, 2, 2, 3, 3, 4]);
+    /// ```
+    pub fn from_iters_ascending<I: IntoIterator<Item = T>>(
+        iters: I,
+    ) -> Tournament<T, MinComparator<T::Item>> {
+        Tournament::from_iters_min(iters)
+    }
 }
 
 impl<T> Tournament<T, MaxComparator<T::Item>>
@@ -41,17 +113,162 @@ where
     ) -> Tournament<T, MaxComparator<T::Item>> {
         Tournament::from_iters(iters, MaxComparator::default())
     }
+
+    /// A tournament that merges sources sorted in descending order, from largest to smallest.
+    /// The same as [`Tournament::from_iters_max`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tournament_kway::Tournament;
+    ///
+    /// let t = Tournament::from_iters_descending([(1..4).rev(), (2..5).rev()]);
+    /// assert_eq!(t.collect::<Vec<_>>(), [4, 3, 3, 2, 2, 1]);
+    /// ```
+    pub fn from_iters_descending<I: IntoIterator<Item = T>>(
+        iters: I,
+    ) -> Tournament<T, MaxComparator<T::Item>> {
+        Tournament::from_iters_max(iters)
+    }
+}
+
+impl<X: Ord> Tournament<std::vec::IntoIter<X>, MinComparator<X>> {
+    /// A tournament that merges owned vectors sorted from smallest to largest, as
+    /// with [`Tournament::from_iters_min`], yielding their elements by value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tournament_kway::Tournament;
+    ///
+    /// let t = Tournament::from_sorted_vecs_min(vec![vec![1, 4], vec![2, 3]]);
+    /// let merged: Vec<i32> = t.collect();
+    /// assert_eq!(merged, [1, 2, 3, 4]);
+    /// ```
+    pub fn from_sorted_vecs_min(vecs: Vec<Vec<X>>) -> Self {
+        Tournament::from_iters_min(vecs.into_iter().map(Vec::into_iter))
+    }
+}
+
+impl<X: Ord> Tournament<std::vec::IntoIter<X>, MaxComparator<X>> {
+    /// A tournament that merges owned vectors sorted from largest to smallest, as
+    /// with [`Tournament::from_iters_max`], yielding their elements by value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tournament_kway::Tournament;
+    ///
+    /// let t = Tournament::from_sorted_vecs_max(vec![vec![4, 1], vec![3, 2]]);
+    /// let merged: Vec<i32> = t.collect();
+    /// assert_eq!(merged, [4, 3, 2, 1]);
+    /// ```
+    pub fn from_sorted_vecs_max(vecs: Vec<Vec<X>>) -> Self {
+        Tournament::from_iters_max(vecs.into_iter().map(Vec::into_iter))
+    }
+}
+
+impl<T> Tournament<T, DirectionComparator<T::Item>>
+where
+    T: Iterator,
+    T::Item: Ord,
+{
+    /// A tournament that rates entries from smallest to largest if `ascending` is
+    /// true, and from largest to smallest otherwise. The provided iterators must
+    /// yeild data in the same direction or the results are undefined.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tournament_kway::Tournament;
+    ///
+    /// let ascending = false;
+    /// let t = Tournament::from_iters_dir([(1..3).rev(), (0..2).rev()], ascending);
+    /// assert_eq!(t.collect::<Vec<_>>(), [2, 1, 1, 0]);
+    /// ```
+    pub fn from_iters_dir<I: IntoIterator<Item = T>>(
+        iters: I,
+        ascending: bool,
+    ) -> Tournament<T, DirectionComparator<T::Item>> {
+        Tournament::from_iters(iters, DirectionComparator::new(ascending))
+    }
+}
+
+impl<T, K, F> Tournament<T, ByKey<T::Item, K, F>>
+where
+    T: Iterator,
+    K: Ord,
+    F: Fn(&T::Item) -> K,
+{
+    /// A tournament that rates entries from smallest to largest key, as extracted
+    /// with `f`. The provided iterators must yeild data from smallest to largest
+    /// key or the results are undefined.
+    ///
+    /// The key is computed on every comparison, see
+    /// [`CachedKeyTournament`](crate::CachedKeyTournament) for keys that are
+    /// expensive to compute.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tournament_kway::Tournament;
+    ///
+    /// let t = Tournament::from_iters_by_key(
+    ///     [vec!["b", "ccc"], vec!["aa"]].map(Vec::into_iter),
+    ///     |s: &&str| s.len(),
+    /// );
+    /// assert_eq!(t.collect::<Vec<_>>(), ["b", "aa", "ccc"]);
+    /// ```
+    pub fn from_iters_by_key<I: IntoIterator<Item = T>>(
+        iters: I,
+        f: F,
+    ) -> Tournament<T, ByKey<T::Item, K, F>> {
+        Tournament::from_iters(iters, by_key(f))
+    }
+}
+
+impl<T> Tournament<T, TotalF64Comparator>
+where
+    T: Iterator<Item = f64>,
+{
+    /// A tournament that rates [`f64`] entries from smallest to largest, according
+    /// to [`f64::total_cmp`].
+    /// The provided iterators must yeild data from smallest to largest
+    /// or the results are undefined.
+    pub fn from_iters_min_f64<I: IntoIterator<Item = T>>(
+        iters: I,
+    ) -> Tournament<T, TotalF64Comparator> {
+        Tournament::from_iters(iters, TotalF64Comparator)
+    }
+}
+
+impl<T> Tournament<T, TotalF32Comparator>
+where
+    T: Iterator<Item = f32>,
+{
+    /// A tournament that rates [`f32`] entries from smallest to largest, according
+    /// to [`f32::total_cmp`].
+    /// The provided iterators must yeild data from smallest to largest
+    /// or the results are undefined.
+    pub fn from_iters_min_f32<I: IntoIterator<Item = T>>(
+        iters: I,
+    ) -> Tournament<T, TotalF32Comparator> {
+        Tournament::from_iters(iters, TotalF32Comparator)
+    }
 }
 
 impl<T, C> Tournament<T, C>
 where
     T: Iterator,
-    C: Comparator<T::Item> + Clone,
+    C: Comparator<T::Item>,
 {
     /// Create a new tournament from a set of iterators and a custom comparator.
     /// The iterators mush have the data sorted using the same semantics used
     /// be the provided comparator.
     ///
+    /// The first element of every source is pulled right away, to build the heap.
+    /// Use [`Tournament::from_iters_lazy`] to defer that until the merge starts.
+    ///
     /// # Examples
     ///
     /// ```
@@ -74,145 +291,3107 @@ where
     /// ```
     ///
     pub fn from_iters<I: IntoIterator<Item = T>>(iters: I, comparator: C) -> Self {
-        let mut tree = BinaryHeap::new();
-        let mut results = Vec::new();
+        Tournament::build(iters, comparator, TieBreak::Any)
+    }
 
-        for (index, mut iter) in iters.into_iter().enumerate() {
-            if let Some(item) = iter.next() {
-                tree.push(TournamentEntry {
-                    item,
-                    index,
-                    comparator: comparator.clone(),
-                });
-            }
-            results.push(iter);
+    /// Create a tournament from a set of iterators and a custom comparator, which
+    /// yields elements that draw in the order of their sources: all the equal
+    /// elements of the first source are yielded before those of the second, and so
+    /// on, as in a stable sort of the concatenated sources. This holds when the
+    /// tournament is also consumed from the back, and for sources added later,
+    /// which come after the existing ones.
+    ///
+    /// Breaking every draw costs a little extra, which is why
+    /// [`Tournament::from_iters`] lets any of the sources win instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tournament_kway::{by_key, Tournament};
+    ///
+    /// let t = Tournament::from_iters_stable(
+    ///     [vec![(1, 'a'), (2, 'b')], vec![(1, 'c'), (2, 'd')], vec![(1, 'e')]].map(Vec::into_iter),
+    ///     by_key(|&(key, _): &(u32, char)| key),
+    /// );
+    /// assert_eq!(t.map(|(_, c)| c).collect::<String>(), "acebd");
+    /// ```
+    pub fn from_iters_stable<I: IntoIterator<Item = T>>(iters: I, comparator: C) -> Self {
+        Tournament::build(iters, comparator, TieBreak::Index)
+    }
+
+    /// Create a tournament that does not touch its sources until the merge starts.
+    /// Unlike [`Tournament::from_iters`], which pulls the first element of every
+    /// source to build the heap, neither `iters` nor the sources are advanced
+    /// until the first call to `next`, which matters when pulling from a source
+    /// has side effects or a cost, such as opening a connection.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::cell::Cell;
+    /// use tournament_kway::{MinComparator, Tournament};
+    ///
+    /// let pulled = Cell::new(0);
+    /// let sources = [(1..3), (2..4)].map(|s| s.inspect(|_| pulled.set(pulled.get() + 1)));
+    /// let mut t = Tournament::from_iters_lazy(sources, MinComparator::default());
+    /// assert_eq!(pulled.get(), 0);
+    /// assert_eq!(t.next(), Some(1));
+    /// assert_eq!(pulled.get(), 3);
+    /// ```
+    pub fn from_iters_lazy<I: IntoIterator<Item = T>>(
+        iters: I,
+        comparator: C,
+    ) -> LazyTournament<I::IntoIter, C> {
+        LazyTournament::new(iters.into_iter(), comparator)
+    }
+
+    /// Create a tournament from a set of iterators, each with a weight, and a custom
+    /// comparator. When the elements of sources draw, the source with the higher
+    /// weight wins, and among those of equal weight the one that was added first,
+    /// for example to prefer the elements of a more authoritative source. This
+    /// holds when the tournament is also consumed from the back.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tournament_kway::{by_key, Tournament};
+    ///
+    /// let t = Tournament::from_weighted_iters(
+    ///     [
+    ///         (vec![(1, "cache"), (2, "cache")].into_iter(), 1),
+    ///         (vec![(1, "primary"), (3, "primary")].into_iter(), 10),
+    ///     ],
+    ///     by_key(|&(key, _): &(u32, &str)| key),
+    /// );
+    /// assert_eq!(
+    ///     t.collect::<Vec<_>>(),
+    ///     [(1, "primary"), (1, "cache"), (2, "cache"), (3, "primary")]
+    /// );
+    /// ```
+    pub fn from_weighted_iters<I: IntoIterator<Item = (T, u32)>>(iters: I, comparator: C) -> Self {
+        let iters = iters.into_iter();
+        let mut tournament = Tournament::empty(iters.size_hint().0, comparator);
+        tournament.tie_break = TieBreak::Weight(Vec::with_capacity(iters.size_hint().0));
+
+        for (iter, weight) in iters {
+            tournament.push_source(iter, weight);
+        }
+        tournament.results.shrink_to_fit();
+        tournament.ids.shrink_to_fit();
+        tournament
+    }
+
+    /// Create a tournament from a set of iterators and a custom comparator, where
+    /// `tie_break` decides which source wins when their elements draw. It is given
+    /// the indices of the two sources among all the sources added, including empty
+    /// ones, and is only called when `comparator` rates their elements as equal, so
+    /// any policy can be built on it, such as source priorities or a seeded shuffle.
+    ///
+    /// The function must be a total order of the sources that does not change
+    /// while merging, or the order of elements that draw is unspecified. It is
+    /// kept for sources added later, and replaced with the one of `self` when
+    /// another tournament is moved in with [`Tournament::merge`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tournament_kway::{by_key, Tournament};
+    ///
+    /// // The last source wins draws
+    /// let t = Tournament::from_iters_with_tiebreak(
+    ///     [vec![(1, 'a'), (2, 'b')], vec![(1, 'c')], vec![(2, 'd')]].map(Vec::into_iter),
+    ///     by_key(|&(key, _): &(u32, char)| key),
+    ///     |a: usize, b: usize| b.cmp(&a),
+    /// );
+    /// assert_eq!(t.map(|(_, c)| c).collect::<String>(), "cadb");
+    /// ```
+    pub fn from_iters_with_tiebreak<I, F>(iters: I, comparator: C, tie_break: F) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        F: Fn(usize, usize) -> Ordering + Send + Sync + 'static,
+    {
+        let iters = iters.into_iter();
+        let mut tournament = Tournament::empty(iters.size_hint().0, comparator);
+        tournament.tie_break =
+            TieBreak::Custom(Arc::new(tie_break), Vec::with_capacity(iters.size_hint().0));
+
+        for iter in iters {
+            tournament.push_iter(iter);
         }
+        tournament.results.shrink_to_fit();
+        tournament.ids.shrink_to_fit();
+        tournament
+    }
+
+    /// Create an empty tournament with room for `k` sources, using a custom comparator.
+    pub fn with_capacity(k: usize, comparator: C) -> Self {
+        Tournament::empty(k, comparator)
+    }
+
+    /// Create a tournament over a fixed number of sources with a custom comparator.
+    /// The sources and the heap are stored inline in arrays, so no allocation is
+    /// made, which suits hot loops where `K` is small and constant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tournament_kway::{MinComparator, Tournament};
+    ///
+    /// let t = Tournament::from_array([(1..2000), (1..20000), (1..5000000)], MinComparator::default());
+    /// assert_eq!(t.take(5).collect::<Vec<_>>(), [1, 1, 1, 2, 2]);
+    /// ```
+    pub fn from_array<const K: usize>(sources: [T; K], comparator: C) -> ArrayTournament<T, C, K> {
+        ArrayTournament::new(sources, comparator)
+    }
+
+    /// Merge sources that do not overlap and are given in order, with a custom
+    /// comparator: the last element of every source must not be ordered after the
+    /// first element of the next one, as with the shards of a range partitioned
+    /// table. The merge is then the concatenation of the sources, which are chained
+    /// instead of compared in a heap. Draws between sources are allowed, and are
+    /// won by the source that comes first.
+    ///
+    /// The order is checked with a debug assertion on every element, which needs
+    /// to look ahead by one element. If the precondition does not hold, the output
+    /// in release builds is simply the concatenation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tournament_kway::{MinComparator, Tournament};
+    ///
+    /// let shards = [(0..100), (100..250), (250..300)];
+    /// let t = Tournament::from_sorted_nonoverlapping(shards, MinComparator::default());
+    /// assert_eq!(t.collect::<Vec<_>>(), (0..300).collect::<Vec<_>>());
+    /// ```
+    pub fn from_sorted_nonoverlapping<I: IntoIterator<Item = T>>(
+        iters: I,
+        comparator: C,
+    ) -> Concatenated<T, C> {
+        Concatenated::new(iters, comparator)
+    }
+
+    /// Merge exactly two sources with a custom comparator. Comparing the two
+    /// current elements directly is considerably faster than maintaining a heap,
+    /// and the output is identical to that of [`Tournament::from_iters`], including
+    /// which source wins a draw.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tournament_kway::{MinComparator, Tournament};
+    ///
+    /// let m = Tournament::merge2(1..4, 2..6, MinComparator::default());
+    /// assert_eq!(m.collect::<Vec<_>>(), [1, 2, 2, 3, 3, 4, 5]);
+    /// ```
+    pub fn merge2(a: T, b: T, comparator: C) -> Merge2<T, C> {
+        Merge2::new(a, b, comparator)
+    }
+
+    /// Select the element at index `k` of the merged output of `iters`, counting
+    /// from 0, so `k = 0` is the first element the comparator rates. Only the first
+    /// `k + 1` elements are pulled through the heap, which is O(k log n) for n
+    /// sources, instead of sorting every element. Returns `None` if the sources
+    /// have `k` elements or fewer in total.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tournament_kway::{MinComparator, Tournament};
+    ///
+    /// let shards = [vec![10, 20, 30, 40], vec![15, 25], vec![35, 45, 55, 65]];
+    /// let total = shards.iter().map(Vec::len).sum::<usize>();
+    ///
+    /// let median = Tournament::select_kth(
+    ///     shards.map(Vec::into_iter),
+    ///     total / 2,
+    ///     MinComparator::default(),
+    /// );
+    /// assert_eq!(median, Some(35));
+    /// ```
+    pub fn select_kth<I: IntoIterator<Item = T>>(
+        iters: I,
+        k: usize,
+        comparator: C,
+    ) -> Option<T::Item> {
+        Tournament::from_iters(iters, comparator).nth(k)
+    }
+
+    /// Find the median of sources whose lengths are known up front, as with
+    /// [`ExactSizeIterator`]. The total number of elements `n` is the sum of the
+    /// lengths, and the tournament advances to the middle, so this takes O(n log k)
+    /// time and O(k) space. For an even `n` the lower median is returned, the
+    /// element at position `n / 2 - 1` in the merged order. Returns `None` if the
+    /// sources are all empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tournament_kway::{MinComparator, Tournament};
+    ///
+    /// let median = Tournament::median([vec![1, 4, 6], vec![2, 9]].map(Vec::into_iter), MinComparator::default());
+    /// assert_eq!(median, Some(4));
+    ///
+    /// let median = Tournament::median([vec![1, 4], vec![2, 9]].map(Vec::into_iter), MinComparator::default());
+    /// assert_eq!(median, Some(2));
+    /// ```
+    pub fn median<I: IntoIterator<Item = T>>(iters: I, comparator: C) -> Option<T::Item>
+    where
+        T: ExactSizeIterator,
+    {
+        let mut tournament = Tournament::from_iters(iters, comparator);
+        // The sources were advanced past their buffered elements, which count too
+        let n = tournament
+            .results
+            .iter()
+            .map(ExactSizeIterator::len)
+            .sum::<usize>()
+            + tournament.tree.len();
+        tournament.nth(n.checked_sub(1)? / 2)
+    }
 
-        Tournament { tree, results }
+    /// Yield the elements of `primary` that are not present in any of `others`, as
+    /// decided by the comparator returning [`Ordering::Equal`]. All the iterators
+    /// must be sorted using the semantics of the provided comparator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tournament_kway::{MinComparator, Tournament};
+    ///
+    /// let d = Tournament::difference(
+    ///     1..10,
+    ///     [vec![2, 3], vec![5, 8, 11]].map(Vec::into_iter),
+    ///     MinComparator::default(),
+    /// );
+    /// assert_eq!(d.collect::<Vec<_>>(), [1, 4, 6, 7, 9]);
+    /// ```
+    pub fn difference<P, I>(primary: P, others: I, comparator: C) -> Difference<P::IntoIter, T, C>
+    where
+        P: IntoIterator<Item = T::Item>,
+        I: IntoIterator<Item = T>,
+    {
+        Difference::new(
+            primary.into_iter(),
+            Tournament::from_iters(others, comparator),
+        )
     }
 }
 
-impl<T, C> Iterator for Tournament<T, C>
+impl<T, C, const D: usize> Tournament<T, C, D>
 where
     T: Iterator,
-    C: Comparator<T::Item> + Clone,
+    C: Comparator<T::Item>,
 {
-    type Item = T::Item;
+    fn build<I: IntoIterator<Item = T>>(iters: I, comparator: C, tie_break: TieBreak) -> Self {
+        let iters = iters.into_iter();
+        let mut tournament = Tournament::empty(iters.size_hint().0, comparator);
+        tournament.tie_break = tie_break;
 
-    fn next(&mut self) -> Option<Self::Item> {
-        match self.tree.pop() {
-            None => None,
-            Some(TournamentEntry {
-                item,
-                index,
-                comparator,
-            }) => {
-                if let Some(item) = self.results[index].next() {
-                    self.tree.push(TournamentEntry {
-                        item,
-                        index,
-                        comparator,
-                    });
+        tournament.extend(iters);
+        // The capacity was reserved for all sources, but empty ones are not kept
+        tournament.results.shrink_to_fit();
+        tournament.ids.shrink_to_fit();
+        tournament
+    }
+
+    fn empty(k: usize, comparator: C) -> Self {
+        Tournament {
+            tree: DaryHeap::with_capacity(k),
+            results: Storage::with_capacity(k),
+            ids: Storage::with_capacity(k),
+            sources: 0,
+            empty: 0,
+            comparator,
+            tie_break: TieBreak::Any,
+            double_ended: None,
+            poisoned: false,
+        }
+    }
+
+    /// Convert the tournament to use a `E`-ary heap. Merges over a large number of
+    /// sources may benefit from a shallower heap, such as a 4-ary one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tournament_kway::Tournament;
+    ///
+    /// let t = Tournament::from_iters_min([(1..2000), (1..20000), (1..5000000)]).with_arity::<4>();
+    /// assert_eq!(t.take(5).collect::<Vec<_>>(), [1, 1, 1, 2, 2]);
+    /// ```
+    pub fn with_arity<const E: usize>(self) -> Tournament<T, C, E> {
+        let mut tree = DaryHeap::with_capacity(self.tree.len());
+        for entry in self.tree.into_vec() {
+            tree.push(entry, entry_order(&self.comparator, &self.tie_break));
+        }
+
+        let double_ended = self.double_ended.map(|double_ended| {
+            Box::new(double_ended.with_arity(&self.comparator, &self.tie_break))
+        });
+
+        Tournament {
+            tree,
+            results: self.results,
+            ids: self.ids,
+            sources: self.sources,
+            empty: self.empty,
+            comparator: self.comparator,
+            tie_break: self.tie_break,
+            double_ended,
+            poisoned: self.poisoned,
+        }
+    }
+
+    /// Add another source to the tournament, pulling its first element.
+    ///
+    /// The new source must be sorted using the semantics of the comparator, and
+    /// its elements must not be ordered before any element the tournament has
+    /// already yielded, otherwise the output is no longer sorted. In a tournament
+    /// created with [`Tournament::from_weighted_iters`] the new source weighs 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tournament_kway::Tournament;
+    ///
+    /// let mut t = Tournament::from_iters_min([(1..4)]);
+    /// assert_eq!(t.next(), Some(1));
+    /// t.push_iter(2..3);
+    /// assert_eq!(t.collect::<Vec<_>>(), [2, 2, 3]);
+    /// ```
+    pub fn push_iter(&mut self, iter: T) {
+        self.push_source(iter, 0);
+    }
+
+    fn push_source(&mut self, mut iter: T, weight: u32) {
+        let id = self.sources;
+        self.sources += 1;
+        // Empty sources are dropped right away, as they will never be advanced
+        if let Some(item) = iter.next() {
+            let index = self.results.len();
+            self.results.push(iter);
+            self.ids.push(id);
+            self.tie_break.push_slot(id, weight);
+            match &mut self.double_ended {
+                Some(double_ended) => {
+                    double_ended.push(index, item, &self.comparator, &self.tie_break)
                 }
-                Some(item)
+                None => self.tree.push(
+                    TournamentEntry { item, index },
+                    entry_order(&self.comparator, &self.tie_break),
+                ),
             }
+        } else {
+            self.empty += 1;
         }
     }
-}
 
-/// An entry into the inner binary tree that implements ['Ord`] over elements
-/// of the tournament
+    /// Merge the remaining output of `other` with this tournament, by moving its
+    /// sources and their buffered elements into this tournament's heap. This
+    /// avoids the extra layer of comparisons of a tournament over the two
+    /// tournaments, and is the way to compose a hierarchy of merges of the same
+    /// source type.
+    ///
+    /// The sources of `other` are then ordered by this tournament's comparator,
+    /// which must agree with the one of `other`. Tournaments with different source
+    /// or comparator types can still be used as the sources of another tournament,
+    /// for example with [`Tournament::merge2`], at the cost of a comparison per
+    /// element on each level.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tournament_kway::Tournament;
+    ///
+    /// let mut a = Tournament::from_iters_min([(1..4), (2..5)]);
+    /// let b = Tournament::from_iters_min([(0..3), (3..4)]);
+    /// assert_eq!(a.next(), Some(1));
+    ///
+    /// let t = a.merge(b);
+    /// assert_eq!(t.active_sources(), 4);
+    /// assert_eq!(t.collect::<Vec<_>>(), [0, 1, 2, 2, 2, 3, 3, 3, 4]);
+    /// ```
+    pub fn merge<const E: usize>(mut self, other: Tournament<T, C, E>) -> Self {
+        let offset = self.results.len();
+        let sources = self.sources;
+        self.sources += other.sources;
+        self.empty += other.empty;
+        let ids = other.ids.iter().map(|id| id + sources).collect::<Vec<_>>();
+        self.tie_break.extend_slots(&other.tie_break, &ids);
 
-#[derive(Clone, Debug)]
+        match other.double_ended {
+            Some(other_double_ended) => {
+                // The buffered back elements of `other` are kept by tracking both
+                // ends of the merged tournament as well
+                let comparator = &self.comparator;
+                let tie_break = &self.tie_break;
+                let double_ended = track_both_ends(
+                    &mut self.double_ended,
+                    &mut self.tree,
+                    offset,
+                    comparator,
+                    tie_break,
+                );
+                double_ended.absorb(*other_double_ended, offset, comparator, tie_break);
+            }
+            None => {
+                for TournamentEntry { item, index } in other.tree.into_vec() {
+                    match &mut self.double_ended {
+                        Some(double_ended) => double_ended.push(
+                            offset + index,
+                            item,
+                            &self.comparator,
+                            &self.tie_break,
+                        ),
+                        None => self.tree.push(
+                            TournamentEntry {
+                                item,
+                                index: offset + index,
+                            },
+                            entry_order(&self.comparator, &self.tie_break),
+                        ),
+                    }
+                }
+            }
+        }
 
-struct TournamentEntry<I, C> {
-    item: I,
-    index: usize,
-    comparator: C,
-}
+        self.results.extend(other.results);
+        self.ids.extend(ids);
+        self
+    }
 
-impl<I, C> Ord for TournamentEntry<I, C>
-where
-    C: Comparator<I>,
-{
-    fn cmp(&self, other: &Self) -> Ordering {
-        self.comparator.cmp(&self.item, &other.item).reverse()
+    /// Advance the tournament, returning the winning element together with the
+    /// slot of the source it came from.
+    pub(crate) fn next_with_index(&mut self) -> Option<(usize, T::Item)> {
+        self.poison();
+        let next = self.advance_heap(false);
+        self.poisoned = false;
+        next.map(|(slot, item, _)| (slot, item))
     }
-}
 
-impl<I, C> PartialOrd for TournamentEntry<I, C>
-where
-    C: Comparator<I>,
-{
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(other))
+    /// Like [`Tournament::next_with_index`], but also returns whether the source
+    /// of the element stayed sorted. A source that did not is dropped, along with
+    /// the element that broke its order, instead of being asserted on.
+    pub(crate) fn next_checked(&mut self) -> Option<(usize, T::Item, bool)> {
+        self.poison();
+        let next = self.advance_heap(true);
+        self.poisoned = false;
+        next
     }
-}
 
-impl<I, C> PartialEq for TournamentEntry<I, C>
-where
-    C: Comparator<I>,
-{
-    fn eq(&self, other: &Self) -> bool {
-        self.cmp(other).is_eq()
+    /// The position in the heap of the entry that wins once the top entry loses, or
+    /// `None` if there is no other entry, or the tournament is consumed from both
+    /// ends.
+    pub(crate) fn runner_up(&self) -> Option<usize> {
+        if self.double_ended.is_some() {
+            return None;
+        }
+        self.tree
+            .runner_up(entry_order(&self.comparator, &self.tie_break))
     }
-}
 
-impl<I, C> Eq for TournamentEntry<I, C> where C: Comparator<I> {}
+    /// Yield the top element, as [`Tournament::next_with_index`] does when the
+    /// tournament is not consumed from both ends. If the next element of its source
+    /// still wins against the entry at `runner_up`, as given by
+    /// [`Tournament::runner_up`], it takes over the top without sifting, and the
+    /// returned flag is set, meaning `runner_up` is still the runner up.
+    pub(crate) fn next_in_run(&mut self, runner_up: usize) -> Option<(T::Item, bool)> {
+        self.poison();
+        let next = self.advance_run(runner_up);
+        self.poisoned = false;
+        next
+    }
 
-#[cfg(test)]
-mod tests {
-    use rand::distributions::{Alphanumeric, DistString};
+    fn advance_run(&mut self, runner_up: usize) -> Option<(T::Item, bool)> {
+        let top = self.tree.peek()?;
+        let index = top.index;
+        let order = entry_order(&self.comparator, &self.tie_break);
 
-    use crate::Tournament;
+        match self.results[index].next() {
+            Some(next) => {
+                debug_assert!(
+                    self.comparator.cmp(&next, &top.item).is_ge(),
+                    "source {index} is not sorted according to the comparator"
+                );
+                let next = TournamentEntry { item: next, index };
+                let (top, kept) = self.tree.replace_top(next, runner_up, order);
+                Some((top.item, kept))
+            }
+            None => Some((self.tree.pop(order)?.item, false)),
+        }
+    }
 
-    #[test]
-    fn test_min() {
-        let mut rng = rand::thread_rng();
+    /// Mark the tournament as being updated, in debug builds, after checking that
+    /// no earlier update panicked.
+    fn poison(&mut self) {
+        if cfg!(debug_assertions) {
+            assert!(
+                !self.poisoned,
+                "the tournament is poisoned, as its comparator panicked in an earlier call"
+            );
+            self.poisoned = true;
+        }
+    }
+
+    fn advance_heap(&mut self, check: bool) -> Option<(usize, T::Item, bool)> {
+        if let Some(double_ended) = &mut self.double_ended {
+            return double_ended.next(&mut self.results, &self.comparator, &self.tie_break, check);
+        }
+
+        let top = self.tree.peek_mut()?;
+        let index = top.index;
+        let mut sorted = true;
+
+        // When the source continues, replace its element in place, and let the heap
+        // sift it down, which is cheaper than a pop followed by a push
+        let item = match self.results[index].next() {
+            Some(next) if check && self.comparator.cmp(&next, &top.item).is_lt() => {
+                sorted = false;
+                self.tree
+                    .pop(entry_order(&self.comparator, &self.tie_break))?
+                    .item
+            }
+            Some(next) => {
+                debug_assert!(
+                    self.comparator.cmp(&next, &top.item).is_ge(),
+                    "source {index} is not sorted according to the comparator"
+                );
+                let item = std::mem::replace(&mut top.item, next);
+                self.tree
+                    .sift_down_top(entry_order(&self.comparator, &self.tie_break));
+                item
+            }
+            None => {
+                self.tree
+                    .pop(entry_order(&self.comparator, &self.tie_break))?
+                    .item
+            }
+        };
+
+        Some((index, item, sorted))
+    }
+
+    /// Collect the first `n` merged elements, or fewer if the sources run out. Only
+    /// those elements are pulled through the heap. With the `rayon` feature,
+    /// `Tournament::par_top_n` splits a very large number of sources between threads.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tournament_kway::Tournament;
+    ///
+    /// let t = Tournament::from_iters_min([(1..2000), (1..20000), (1..5000000)]);
+    /// assert_eq!(t.top_n(5), [1, 1, 1, 2, 2]);
+    /// ```
+    pub fn top_n(self, n: usize) -> Vec<T::Item> {
+        self.take(n).collect()
+    }
+
+    /// Merge all the remaining elements into a [`Vec`], leaving the tournament
+    /// exhausted. Unlike collecting the tournament by value, it can then be refilled
+    /// with [`Tournament::push_iter`] or [`Extend`].
+    pub fn drain_remaining(&mut self) -> Vec<T::Item> {
+        let mut remaining = Vec::new();
+        while let Some((_, item)) = self.next_with_index() {
+            remaining.push(item);
+        }
+        remaining
+    }
+
+    /// Merge all the elements into any container that implements [`Extend`], such
+    /// as a [`Vec`] that already holds elements, a
+    /// [`BTreeSet`](std::collections::BTreeSet), or an adapter over a channel. The
+    /// elements are handed over in merged order, in a single call to `extend`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tournament_kway::Tournament;
+    ///
+    /// let mut merged = vec![0];
+    /// Tournament::from_iters_min([(1..3), (2..4)]).collect_into(&mut merged);
+    /// assert_eq!(merged, [0, 1, 2, 2, 3]);
+    /// ```
+    pub fn collect_into<E: Extend<T::Item>>(self, container: &mut E) {
+        container.extend(self);
+    }
+
+    /// Yield merged elements for as long as `pred` holds for them, like
+    /// [`Iterator::take_while`], but borrowing the tournament. Every element is
+    /// tested before it is pulled from the heap, so the first one `pred` rejects
+    /// stays in the tournament, and is the next one it yields. This allows a merge
+    /// to be consumed in ranges of keys.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tournament_kway::Tournament;
+    ///
+    /// let mut t = Tournament::from_iters_min([(1..6), (3..8)]);
+    /// assert_eq!(t.take_while_key(|&i| i < 4).collect::<Vec<_>>(), [1, 2, 3, 3]);
+    /// assert_eq!(t.collect::<Vec<_>>(), [4, 4, 5, 5, 6, 7]);
+    /// ```
+    pub fn take_while_key<F>(&mut self, pred: F) -> TakeWhileKey<'_, T, C, F, D>
+    where
+        F: FnMut(&T::Item) -> bool,
+    {
+        TakeWhileKey::new(self, pred)
+    }
+
+    /// Fold the merged elements until `f` returns [`ControlFlow::Break`], which is
+    /// returned immediately. This is [`Iterator::try_fold`] for [`ControlFlow`],
+    /// written as an inherent method because `try_fold` itself cannot be
+    /// overridden on stable Rust.
+    ///
+    /// The tournament is left consistent when the fold breaks, so it can be
+    /// resumed. The element `f` broke on was already removed from the merge: it is
+    /// not yielded again, and is lost unless `f` returns it in the break value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ops::ControlFlow;
+    /// use tournament_kway::Tournament;
+    ///
+    /// let mut t = Tournament::from_iters_min([vec![1, 4, 9], vec![2, 3, 10]].map(Vec::into_iter));
+    /// let sum = t.fold_while(0, |sum, i| {
+    ///     if i > 3 {
+    ///         ControlFlow::Break((sum, i))
+    ///     } else {
+    ///         ControlFlow::Continue(sum + i)
+    ///     }
+    /// });
+    /// assert_eq!(sum, ControlFlow::Break((6, 4)));
+    /// assert_eq!(t.collect::<Vec<_>>(), [9, 10]);
+    /// ```
+    pub fn fold_while<B, R, F>(&mut self, init: B, mut f: F) -> ControlFlow<R, B>
+    where
+        F: FnMut(B, T::Item) -> ControlFlow<R, B>,
+    {
+        let mut acc = init;
+        while let Some((_, item)) = self.next_with_index() {
+            acc = f(acc, item)?;
+        }
+        ControlFlow::Continue(acc)
+    }
+
+    /// Drop the active sources whose next element does not satisfy `pred`, along
+    /// with that element, for example to prune shards that are past a cutoff
+    /// mid-merge. The other sources keep their order, and their indices shift down
+    /// past the dropped ones, as seen by [`Tournament::sources`]. Exhausted sources
+    /// are kept.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tournament_kway::Tournament;
+    ///
+    /// let mut t = Tournament::from_iters_min([vec![1, 5], vec![2, 3], vec![4, 6]].map(Vec::into_iter));
+    /// assert_eq!(t.next(), Some(1));
+    /// t.retain_sources(|&head| head < 5);
+    /// assert_eq!(t.collect::<Vec<_>>(), [2, 3, 4, 6]);
+    /// ```
+    pub fn retain_sources<F>(&mut self, pred: F)
+    where
+        F: FnMut(&T::Item) -> bool,
+    {
+        let remap = self.remap_slots(true, pred);
+        if remap.iter().all(Option::is_some) {
+            return;
+        }
+
+        let mut keep = remap.iter();
+        self.results.retain(|_| keep.next().unwrap().is_some());
+        let mut keep = remap.iter();
+        self.ids.retain(|_| keep.next().unwrap().is_some());
+        self.tie_break.retain_slots(&remap);
+
+        match &mut self.double_ended {
+            Some(double_ended) => double_ended.retain(&remap, &self.comparator, &self.tie_break),
+            None => {
+                let entries = std::mem::replace(&mut self.tree, DaryHeap::with_capacity(0));
+                self.tree = remap_tree(
+                    entries.into_vec(),
+                    &remap,
+                    &self.comparator,
+                    &self.tie_break,
+                );
+            }
+        }
+    }
+
+    /// Clone the sources that are still active and their buffered elements into a
+    /// new tournament, leaving out the exhausted ones, which
+    /// [`Clone`](Tournament::clone) carries along. The clone yields the same
+    /// remaining output, and is cheaper to fork when many of the sources are done.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tournament_kway::Tournament;
+    ///
+    /// let mut t = Tournament::from_iters_min([vec![1], vec![2, 4], vec![3]].map(Vec::into_iter));
+    /// assert_eq!(t.next(), Some(1));
+    /// let fork = t.clone_active();
+    /// assert_eq!(fork.sources().len(), 2);
+    /// assert_eq!(fork.collect::<Vec<_>>(), t.collect::<Vec<_>>());
+    /// ```
+    pub fn clone_active(&self) -> Self
+    where
+        T: Clone,
+        T::Item: Clone,
+        C: Clone,
+    {
+        let remap = self.remap_slots(false, |_| true);
+
+        let mut tie_break = self.tie_break.clone();
+        tie_break.retain_slots(&remap);
+        let (tree, double_ended) = match &self.double_ended {
+            Some(double_ended) => {
+                let mut double_ended = double_ended.clone();
+                double_ended.retain(&remap, &self.comparator, &tie_break);
+                (DaryHeap::with_capacity(0), Some(double_ended))
+            }
+            None => (
+                remap_tree(
+                    self.tree.iter().cloned(),
+                    &remap,
+                    &self.comparator,
+                    &tie_break,
+                ),
+                None,
+            ),
+        };
+
+        Tournament {
+            tree,
+            results: (self.results.iter().zip(&remap))
+                .filter_map(|(source, slot)| slot.map(|_| source.clone()))
+                .collect(),
+            ids: (self.ids.iter().zip(&remap))
+                .filter_map(|(&id, slot)| slot.map(|_| id))
+                .collect(),
+            sources: self.sources,
+            empty: self.empty,
+            comparator: self.comparator.clone(),
+            tie_break,
+            double_ended,
+            poisoned: self.poisoned,
+        }
+    }
+
+    /// Number the slots that are kept, in order, which are the active slots for
+    /// whose element `keep` returns `true`, and the exhausted ones if `exhausted`
+    /// is set.
+    fn remap_slots<F>(&self, exhausted: bool, mut keep: F) -> Vec<Option<usize>>
+    where
+        F: FnMut(&T::Item) -> bool,
+    {
+        let mut kept = vec![exhausted; self.results.len()];
+        match &self.double_ended {
+            Some(double_ended) => {
+                for (slot, item) in double_ended.active() {
+                    kept[slot] = keep(item);
+                }
+            }
+            None => {
+                for entry in self.tree.iter() {
+                    kept[entry.index] = keep(&entry.item);
+                }
+            }
+        }
+
+        // The kept slots move down past the dropped ones
+        let mut slots = 0;
+        kept.into_iter()
+            .map(|k| {
+                k.then(|| {
+                    slots += 1;
+                    slots - 1
+                })
+            })
+            .collect()
+    }
+
+    /// Reserve room for at least `additional` more sources, so adding a large batch
+    /// of them with [`Extend`] or [`Tournament::push_iter`] does not reallocate the
+    /// heap and the sources along the way.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tournament_kway::Tournament;
+    ///
+    /// let mut t = Tournament::from_iters_min([(1..4)]);
+    /// t.reserve(100);
+    /// assert!(t.heap_capacity() >= 101);
+    /// t.extend((0..100).map(|i| i..i + 2));
+    /// assert_eq!(t.count(), 203);
+    /// ```
+    pub fn reserve(&mut self, additional: usize) {
+        self.tree.reserve(additional);
+        self.results.reserve(additional);
+        self.ids.reserve(additional);
+    }
+
+    /// Drop all the sources and their buffered elements, keeping the allocated
+    /// capacity, so the tournament can be refilled with [`Tournament::push_iter`]
+    /// or [`Extend`] for another merge without reallocating.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tournament_kway::Tournament;
+    ///
+    /// let mut t = Tournament::from_iters_min([(1..4), (2..6)]);
+    /// assert_eq!(t.next(), Some(1));
+    /// t.clear();
+    /// t.extend([(7..9), (8..10)]);
+    /// assert_eq!(t.collect::<Vec<_>>(), [7, 8, 8, 9]);
+    /// ```
+    pub fn clear(&mut self) {
+        self.tree.clear();
+        self.results.clear();
+        self.ids.clear();
+        self.sources = 0;
+        self.empty = 0;
+        self.tie_break.clear_slots();
+        self.double_ended = None;
+        self.poisoned = false;
+    }
+
+    /// Yield the merged output in batches of `n` elements, the last batch possibly
+    /// being shorter.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tournament_kway::Tournament;
+    ///
+    /// let t = Tournament::from_iters_min([(1..4), (2..4)]);
+    /// assert_eq!(t.chunks(2).collect::<Vec<_>>(), [vec![1, 2], vec![2, 3], vec![3]]);
+    /// ```
+    pub fn chunks(self, n: usize) -> Chunks<T, C, D> {
+        Chunks::new(self, n)
+    }
+
+    /// Yield every `n`th merged element, starting with the first, like
+    /// [`Iterator::step_by`] but keeping access to the state of the tournament.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tournament_kway::Tournament;
+    ///
+    /// let t = Tournament::from_iters_min([(0..6), (3..6)]);
+    /// assert_eq!(t.step_by(3).collect::<Vec<_>>(), [0, 3, 4]);
+    /// ```
+    pub fn step_by(self, n: usize) -> StepBy<T, C, D> {
+        StepBy::new(self, n)
+    }
+
+    /// Apply `f` to every merged element. The function must be monotonic under the
+    /// comparator, that is, it must never map an element to something ordered
+    /// before what a previous element was mapped to, so the output stays sorted
+    /// and can be deduplicated with [`MapMonotonic::union`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tournament_kway::Tournament;
+    ///
+    /// let t = Tournament::from_iters_min([(1..3), (2..4)]);
+    /// let m = t.map_monotonic(|i| i * 10);
+    /// assert_eq!(m.collect::<Vec<_>>(), [10, 20, 20, 30]);
+    /// ```
+    pub fn map_monotonic<U, F>(self, f: F) -> MapMonotonic<T, C, F, D>
+    where
+        F: FnMut(T::Item) -> U,
+    {
+        MapMonotonic::new(self, f)
+    }
+
+    /// Yield only the merged elements for which `predicate` returns `true`. Unlike
+    /// [`Iterator::filter`], the adapter can still [`peek`](Filter::peek) at the
+    /// next accepted element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tournament_kway::Tournament;
+    ///
+    /// let mut t = Tournament::from_iters_min([(1..4), (2..6)]).filter(|i| i % 2 == 1);
+    /// assert_eq!(t.peek(), Some(&1));
+    /// assert_eq!(t.collect::<Vec<_>>(), [1, 3, 3, 5]);
+    /// ```
+    pub fn filter<P>(self, predicate: P) -> Filter<T, C, P, D>
+    where
+        P: FnMut(&T::Item) -> bool,
+    {
+        Filter::new(self, predicate)
+    }
+
+    /// Map the merged elements with `f`, yielding only the ones it returns `Some`
+    /// for. Unlike [`Iterator::filter_map`], the adapter can still
+    /// [`peek`](FilterMap::peek) at the next accepted element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tournament_kway::Tournament;
+    ///
+    /// let mut t = Tournament::from_iters_min([(1..4), (2..6)])
+    ///     .filter_map(|i| (i % 2 == 0).then(|| i.to_string()));
+    /// assert_eq!(t.peek().map(String::as_str), Some("2"));
+    /// assert_eq!(t.collect::<Vec<_>>(), ["2", "2", "4"]);
+    /// ```
+    pub fn filter_map<U, F>(self, f: F) -> FilterMap<T, C, F, U, D>
+    where
+        F: FnMut(T::Item) -> Option<U>,
+    {
+        FilterMap::new(self, f)
+    }
+
+    /// The element the comparator rates last among all the remaining elements,
+    /// which for a tournament from smallest to largest is the global maximum.
+    ///
+    /// Unlike [`Tournament::min_element`] this is not free: the greatest element
+    /// is located at the end of one of the sources, so every source is drained,
+    /// as with [`Iterator::last`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tournament_kway::Tournament;
+    ///
+    /// let t = Tournament::from_iters_min([(1..4), (2..6)]);
+    /// assert_eq!(t.min_element(), Some(&1));
+    /// assert_eq!(t.max_element(), Some(5));
+    /// ```
+    pub fn max_element(self) -> Option<T::Item> {
+        self.last()
+    }
+
+    /// Collect consecutive merged elements with equal keys, as returned by
+    /// `key_fn`, into groups. A group is yielded as soon as an element with a
+    /// different key is seen, so the sources must be sorted such that equal keys
+    /// are adjacent, for example with a [`by_key`](crate::by_key) comparator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tournament_kway::Tournament;
+    ///
+    /// let t = Tournament::from_iters_min([vec![10, 11, 20], vec![12, 30]].map(Vec::into_iter));
+    /// let groups = t.group_by_key(|i| i / 10).collect::<Vec<_>>();
+    /// assert_eq!(groups, [(1, vec![10, 11, 12]), (2, vec![20]), (3, vec![30])]);
+    /// ```
+    pub fn group_by_key<K, F>(self, key_fn: F) -> GroupByKey<T, C, F, D>
+    where
+        F: FnMut(&T::Item) -> K,
+        K: PartialEq,
+    {
+        GroupByKey::new(self, key_fn)
+    }
+
+    /// Yield at most the first `n` merged elements, like [`Iterator::take`], while
+    /// keeping access to the state of the tournament and the number of elements
+    /// left to yield.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tournament_kway::Tournament;
+    ///
+    /// let mut t = Tournament::from_iters_min([(1..2000), (1..20000)]).limit(3);
+    /// assert_eq!(t.peek(), Some(&1));
+    /// assert_eq!(t.by_ref().collect::<Vec<_>>(), [1, 1, 2]);
+    /// assert_eq!(t.remaining_budget(), 0);
+    /// ```
+    pub fn limit(self, n: usize) -> LimitedTournament<T, C, D> {
+        LimitedTournament::new(self, n)
+    }
+
+    /// Yield merged elements until `deadline` passes, for a best effort merge
+    /// within a time budget. The clock is checked before every element, so the
+    /// granularity is one element: an element that is slow to pull from its source
+    /// can overrun the deadline by the time it takes. The tournament can be taken
+    /// back with [`TakeUntil::into_inner`] to resume the merge later.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::{Duration, Instant};
+    /// use tournament_kway::Tournament;
+    ///
+    /// let t = Tournament::from_iters_min([(1..4), (2..5)]);
+    /// let mut t = t.take_until(Instant::now() + Duration::from_secs(60));
+    /// assert_eq!(t.by_ref().count(), 6);
+    ///
+    /// let mut t = t.into_inner().take_until(Instant::now());
+    /// assert_eq!(t.next(), None);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn take_until(self, deadline: std::time::Instant) -> TakeUntil<T, C, D> {
+        TakeUntil::new(self, deadline)
+    }
+
+    /// Call `f` with every merged element and the index of the source it came
+    /// from, before yielding the element, like [`Iterator::inspect`]. Sources are
+    /// indexed in the order they were added to the tournament, counting the empty
+    /// ones, for example to log how often each shard wins.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tournament_kway::Tournament;
+    ///
+    /// let mut wins = [0; 3];
+    /// let t = Tournament::from_iters_min([(0..2), (0..0), (1..5)]);
+    /// let merged = t.inspect_source(|source, _| wins[source] += 1).collect::<Vec<_>>();
+    /// assert_eq!(merged, [0, 1, 1, 2, 3, 4]);
+    /// assert_eq!(wins, [2, 0, 4]);
+    /// ```
+    pub fn inspect_source<F>(self, f: F) -> InspectSource<T, C, F, D>
+    where
+        F: FnMut(usize, &T::Item),
+    {
+        InspectSource::new(self, f)
+    }
+
+    /// Lend the merged elements instead of moving them out, as a
+    /// [`StreamingIterator`]: every element stays in the adapter, and
+    /// [`StreamingIterator::get`] returns a reference to it that borrows the
+    /// adapter. The borrow checker ends the borrow before the next call to
+    /// [`StreamingIterator::advance`], which drops the element to make room for the
+    /// next, so an element has to be cloned to outlive it. This saves moving large
+    /// elements down a pipeline that only has to look at them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use streaming_iterator::StreamingIterator;
+    /// use tournament_kway::Tournament;
+    ///
+    /// let a = vec![String::from("apple"), String::from("kiwi")];
+    /// let b = vec![String::from("banana")];
+    /// let mut t = Tournament::from_iters_min([a, b].map(Vec::into_iter)).lending();
+    /// let mut lengths = Vec::new();
+    /// while let Some(fruit) = t.next() {
+    ///     lengths.push(fruit.len());
+    /// }
+    /// assert_eq!(lengths, [5, 6, 4]);
+    /// ```
+    ///
+    /// [`StreamingIterator`]: streaming_iterator::StreamingIterator
+    /// [`StreamingIterator::get`]: streaming_iterator::StreamingIterator::get
+    /// [`StreamingIterator::advance`]: streaming_iterator::StreamingIterator::advance
+    pub fn lending(self) -> Lending<T, C, D> {
+        Lending::new(self)
+    }
+
+    /// Turn the tournament into a [`StreamingIterator`] over its merged elements,
+    /// with [`streaming_iterator::convert`], for code that expects one, such as a
+    /// [`StreamingTournament`](crate::StreamingTournament) over several merges.
+    /// The elements are moved into the adapter, as with [`Tournament::lending`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use streaming_iterator::StreamingIterator;
+    /// use tournament_kway::{StreamingTournament, Tournament};
+    ///
+    /// let evens = Tournament::from_iters_min([vec![0, 4], vec![2, 6]].map(Vec::into_iter));
+    /// let odds = Tournament::from_iters_min([vec![1, 3], vec![5]].map(Vec::into_iter));
+    /// let mut t = StreamingTournament::from_iters_min([evens.into_streaming(), odds.into_streaming()]);
+    /// let mut merged = Vec::new();
+    /// while let Some(&item) = t.next() {
+    ///     merged.push(item);
+    /// }
+    /// assert_eq!(merged, [0, 1, 2, 3, 4, 5, 6]);
+    /// ```
+    ///
+    /// [`StreamingIterator`]: streaming_iterator::StreamingIterator
+    pub fn into_streaming(self) -> streaming_iterator::Convert<Self> {
+        streaming_iterator::convert(self)
+    }
+
+    /// Keep running statistics over the merged elements while yielding them: how
+    /// many were yielded, how many came from every source, and the largest the
+    /// heap grew. Unlike [`Tournament::inspect_source`], which calls back for every
+    /// element, the stats are aggregated, and [`WithStats::stats`] can be checked
+    /// at any point of the merge, for example to tell if one source dominates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tournament_kway::Tournament;
+    ///
+    /// let t = Tournament::from_iters_min([vec![0, 5], vec![1, 2, 3, 4]].map(Vec::into_iter));
+    /// let mut t = t.with_stats();
+    /// assert_eq!(t.by_ref().take(4).collect::<Vec<_>>(), [0, 1, 2, 3]);
+    /// assert_eq!(t.stats().emitted, 4);
+    /// assert_eq!(t.stats().per_source_counts, [1, 3]);
+    /// assert_eq!(t.stats().max_heap_len, 2);
+    /// ```
+    pub fn with_stats(self) -> WithStats<T, C, D> {
+        WithStats::new(self)
+    }
+
+    /// Yield the merged output as maximal runs of consecutive elements drawn from
+    /// the same source, together with the index of that source, counting the empty
+    /// ones as with [`Tournament::inspect_source`]. A run ends when another source
+    /// wins the next element, so the runs reveal how the sources interleave.
+    ///
+    /// Which source wins a draw is unspecified, unless the tournament was created
+    /// with [`Tournament::from_iters_stable`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tournament_kway::Tournament;
+    ///
+    /// let t = Tournament::from_iters_min([vec![1, 2, 6], vec![3, 4, 5]].map(Vec::into_iter));
+    /// assert_eq!(
+    ///     t.runs().collect::<Vec<_>>(),
+    ///     [(0, vec![1, 2]), (1, vec![3, 4, 5]), (0, vec![6])]
+    /// );
+    /// ```
+    pub fn runs(self) -> Runs<T, C, D> {
+        Runs::new(self)
+    }
+
+    /// Yield the merged elements in runs of up to `max_run` elements from the same
+    /// source, for as long as the source keeps winning. While a source keeps
+    /// winning, every element is compared against the runner up alone, instead of
+    /// sifting it down the heap, which saves comparisons and keeps reading from the
+    /// same source when one of them dominates. The output is the same as that of
+    /// the tournament.
+    ///
+    /// After `max_run` elements the heap is checked again for its runner up. When
+    /// the sources interleave element by element, it makes as many comparisons as
+    /// the tournament, so it pays off for sources that take turns in blocks.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_run` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tournament_kway::Tournament;
+    ///
+    /// let t = Tournament::from_iters_min([(0..1000), (500..502)]);
+    /// let merged = t.coalesce(64).collect::<Vec<_>>();
+    /// assert_eq!(merged.len(), 1002);
+    /// assert_eq!(merged[499..504], [499, 500, 500, 501, 501]);
+    /// ```
+    pub fn coalesce(self, max_run: usize) -> Coalesce<T, C, D> {
+        Coalesce::new(self, max_run)
+    }
+
+    /// Yield every merged element together with its rank, its 0-based position in
+    /// the merged order, like [`Iterator::enumerate`] but keeping access to the
+    /// state of the tournament. Elements yielded before are not counted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tournament_kway::Tournament;
+    ///
+    /// let t = Tournament::from_iters_max([vec![9, 3], vec![5]].map(Vec::into_iter));
+    /// assert_eq!(t.ranked().collect::<Vec<_>>(), [(0, 9), (1, 5), (2, 3)]);
+    /// ```
+    pub fn ranked(self) -> Ranked<T, C, D> {
+        Ranked::new(self)
+    }
+
+    /// Validate the sources lazily while merging, yielding `Err` as soon as a
+    /// source yields an element ordered before its predecessor, instead of
+    /// collecting every source up front like [`Tournament::try_from_iters`]. The
+    /// error names the index of the source, counting the empty ones, and the
+    /// position of the element within what the source yielded from the element it
+    /// had buffered when `checked` was called.
+    ///
+    /// The merged elements before the violation are yielded as `Ok`, and nothing
+    /// is yielded after the `Err`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tournament_kway::Tournament;
+    ///
+    /// let t = Tournament::from_iters_min([vec![1, 4, 2], vec![3, 5]].map(Vec::into_iter));
+    /// let mut checked = t.checked();
+    /// assert_eq!(checked.next(), Some(Ok(1)));
+    /// assert_eq!(checked.next(), Some(Ok(3)));
+    /// assert_eq!(checked.next(), Some(Ok(4)));
+    /// let err = checked.next().unwrap().unwrap_err();
+    /// assert_eq!((err.source_index(), err.position()), (0, 2));
+    /// assert_eq!(checked.next(), None);
+    /// ```
+    pub fn checked(self) -> Checked<T, C, D> {
+        Checked::new(self)
+    }
+
+    /// Allow looking at the next merged element without yielding it, like
+    /// [`Iterator::peekable`], while keeping access to [`Tournament::active_sources`].
+    /// [`PeekableTournament::peek_many`] looks further ahead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tournament_kway::Tournament;
+    ///
+    /// let mut t = Tournament::from_iters_min([(1..3), (2..4)]).peekable();
+    /// assert_eq!(t.peek(), Some(&1));
+    /// assert_eq!(t.collect::<Vec<_>>(), [1, 2, 2, 3]);
+    /// ```
+    pub fn peekable(self) -> PeekableTournament<T, C, D> {
+        PeekableTournament::new(self)
+    }
+
+    /// Yield only the elements that appear in *every* source, as decided by the
+    /// comparator returning [`Ordering::Equal`]. Each such element is yielded once.
+    /// If any of the sources is empty, so is the intersection.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tournament_kway::Tournament;
+    ///
+    /// let t = Tournament::from_iters_min(
+    ///     [vec![1, 2, 4, 6], vec![2, 3, 4, 6], vec![0, 2, 6]].map(Vec::into_iter),
+    /// );
+    /// assert_eq!(t.intersection().collect::<Vec<_>>(), [2, 6]);
+    /// ```
+    pub fn intersection(self) -> Intersection<T, C, D> {
+        Intersection::new(self)
+    }
+
+    /// Yield at most `n` elements out of every run of equal elements, as decided by
+    /// the comparator returning [`Ordering::Equal`], skipping the rest of the run.
+    /// With `n` of 1 this is the same as [`Tournament::union`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tournament_kway::Tournament;
+    ///
+    /// let t = Tournament::from_iters_min([vec![1, 1, 2], vec![1, 2, 3]].map(Vec::into_iter));
+    /// assert_eq!(t.dedup_keep(2).collect::<Vec<_>>(), [1, 1, 2, 2, 3]);
+    /// ```
+    pub fn dedup_keep(self, n: usize) -> DedupKeep<T, C, D> {
+        DedupKeep::new(self, n)
+    }
+
+    /// Yield every distinct element across all sources exactly once, as decided by
+    /// the comparator returning [`Ordering::Equal`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tournament_kway::Tournament;
+    ///
+    /// let t = Tournament::from_iters_min([(1..4), (2..6), (5..7)]);
+    /// assert_eq!(t.union().collect::<Vec<_>>(), [1, 2, 3, 4, 5, 6]);
+    /// ```
+    pub fn union(self) -> Union<T, C, D> {
+        Union::new(self)
+    }
+}
+
+impl<I, C> Tournament<std::vec::IntoIter<I>, C>
+where
+    C: Comparator<I>,
+{
+    /// Create a new tournament from a set of sources and a custom comparator,
+    /// verifying that every source is sorted using the semantics of the comparator.
+    ///
+    /// Every source is collected into a [`Vec`] before the merge starts, so this is
+    /// meant for inputs that are small enough to validate up front.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tournament_kway::{MinComparator, Tournament};
+    ///
+    /// let err = Tournament::try_from_iters([vec![1, 2, 3], vec![1, 3, 2]], MinComparator::default())
+    ///     .unwrap_err();
+    /// assert_eq!((err.source_index(), err.position()), (1, 2));
+    /// ```
+    pub fn try_from_iters<S, J>(iters: J, comparator: C) -> Result<Self, UnsortedError>
+    where
+        S: IntoIterator<Item = I>,
+        J: IntoIterator<Item = S>,
+    {
+        let sources = iters
+            .into_iter()
+            .enumerate()
+            .map(|(index, source)| {
+                let source = source.into_iter().collect::<Vec<_>>();
+                match source
+                    .windows(2)
+                    .position(|pair| comparator.cmp(&pair[1], &pair[0]).is_lt())
+                {
+                    Some(position) => Err(UnsortedError::new(index, position + 1)),
+                    None => Ok(source.into_iter()),
+                }
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Tournament::from_iters(sources, comparator))
+    }
+}
+
+impl<'a, I, T, C> Tournament<T, RefComparator<C>>
+where
+    I: ?Sized + 'a,
+    T: Iterator<Item = &'a I>,
+    C: Comparator<I>,
+{
+    /// Create a tournament over sources that yield borrowed items, with a
+    /// comparator of the items themselves. The items may be unsized, like [`str`]
+    /// or `[u8]`, and only the references are kept in the heap, so nothing is copied.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tournament_kway::{NaturalComparator, Tournament};
+    ///
+    /// let a = "file1\nfile10\n";
+    /// let b = "file2\nfile20\n";
+    /// let t = Tournament::from_iters_ref([a.lines(), b.lines()], NaturalComparator);
+    /// assert_eq!(t.collect::<Vec<&str>>(), ["file1", "file2", "file10", "file20"]);
+    /// ```
+    pub fn from_iters_ref<J: IntoIterator<Item = T>>(iters: J, comparator: C) -> Self {
+        Tournament::from_iters(iters, RefComparator(comparator))
+    }
+}
+
+impl<'a, X, C> Tournament<Box<dyn Iterator<Item = X> + 'a>, C>
+where
+    C: Comparator<X>,
+{
+    /// Create a tournament over sources of different types that yield the same
+    /// items, such as a slice iterator, a range and a `map` adapter, with a custom
+    /// comparator. Every source is dynamically dispatched, which makes advancing
+    /// them somewhat slower than with [`Tournament::from_iters`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tournament_kway::{MinComparator, Tournament};
+    ///
+    /// let evens = [0, 2, 4];
+    /// let t = Tournament::from_boxed_iters(
+    ///     vec![
+    ///         Box::new(evens.iter().copied()),
+    ///         Box::new(1..4),
+    ///         Box::new((0..3).map(|i| i * 3)),
+    ///     ],
+    ///     MinComparator::default(),
+    /// );
+    /// assert_eq!(t.collect::<Vec<_>>(), [0, 0, 1, 2, 2, 3, 3, 4, 6]);
+    /// ```
+    pub fn from_boxed_iters(iters: Vec<Box<dyn Iterator<Item = X> + 'a>>, comparator: C) -> Self {
+        Tournament::from_iters(iters, comparator)
+    }
+}
+
+impl<S, F, C> Tournament<std::iter::Map<S, F>, C>
+where
+    S: Iterator,
+    F: FnMut(S::Item) -> S::Item,
+    C: Comparator<S::Item>,
+{
+    /// Create a tournament over sources that are each paired with a transform,
+    /// which is applied to every element of the source before it is compared, for
+    /// example to normalize the units of sources that come from different systems.
+    ///
+    /// Every transform has to preserve the order of its source according to the
+    /// comparator. As the transforms share a type, different closures have to be
+    /// given as function pointers or boxed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tournament_kway::{MinComparator, Tournament};
+    ///
+    /// let secs = vec![1, 3];
+    /// let millis = vec![2000];
+    /// let t = Tournament::from_iters_with_transforms(
+    ///     [
+    ///         (secs, (|s| s * 1000) as fn(u64) -> u64),
+    ///         (millis, |ms| ms),
+    ///     ],
+    ///     MinComparator::default(),
+    /// );
+    /// assert_eq!(t.collect::<Vec<_>>(), [1000, 2000, 3000]);
+    /// ```
+    pub fn from_iters_with_transforms<I, J>(iters_and_fns: I, comparator: C) -> Self
+    where
+        I: IntoIterator<Item = (J, F)>,
+        J: IntoIterator<IntoIter = S>,
+    {
+        Tournament::from_iters(
+            iters_and_fns
+                .into_iter()
+                .map(|(iter, f)| iter.into_iter().map(f)),
+            comparator,
+        )
+    }
+}
+
+impl<'a, I, C> Tournament<&'a mut I, C>
+where
+    I: Iterator,
+    C: Comparator<I::Item>,
+{
+    /// Create a tournament that borrows its sources instead of taking them, with a
+    /// custom comparator, so the caller keeps them for another merge after this one
+    /// stops, for example after a [`Tournament::take_while_key`].
+    ///
+    /// The tournament buffers one element of every active source, which is pulled
+    /// from the source but not yielded yet. Dropping the tournament drops those
+    /// elements, so use [`Tournament::into_inner`] to take them back when the
+    /// merge resumes later.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tournament_kway::{MinComparator, Tournament};
+    ///
+    /// let mut sources = [(1..6), (10..12)];
+    /// let mut t = Tournament::from_iters_by_ref(&mut sources, MinComparator::default());
+    /// assert_eq!(t.by_ref().take(3).collect::<Vec<_>>(), [1, 2, 3]);
+    /// let buffered = t.into_inner().into_iter().map(|(item, _)| item).collect::<Vec<_>>();
+    /// assert_eq!(buffered, [Some(4), Some(10)]);
+    /// assert_eq!(sources, [(5..6), (11..12)]);
+    /// ```
+    pub fn from_iters_by_ref(iters: &'a mut [I], comparator: C) -> Self {
+        Tournament::from_iters(iters.iter_mut(), comparator)
+    }
+}
+
+impl<'a, I, C> Tournament<std::slice::Iter<'a, I>, C>
+where
+    C: Comparator<I>,
+{
+    /// Create a tournament over sorted slices with a custom comparator. Whenever a
+    /// slice wins, the run of elements it would keep winning with is found with an
+    /// exponential search and yielded directly, skipping the heap. This is much
+    /// faster than [`Tournament::from_iters`] when a few sources dominate the merge.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tournament_kway::{MinComparator, Tournament};
+    ///
+    /// let big = (1..5000000).collect::<Vec<_>>();
+    /// let small = (1..2000).collect::<Vec<_>>();
+    /// let t = Tournament::from_sorted_slices([&big[..], &small[..]], MinComparator::default());
+    /// assert_eq!(t.take(5).collect::<Vec<_>>(), [&1, &1, &2, &2, &3]);
+    /// ```
+    pub fn from_sorted_slices<S>(slices: S, comparator: C) -> GallopingTournament<'a, I, C>
+    where
+        S: IntoIterator<Item = &'a [I]>,
+    {
+        GallopingTournament::new(slices, comparator)
+    }
+}
+
+impl<T, C, const D: usize> Tournament<T, C, D>
+where
+    T: Iterator,
+{
+    /// The number of sources that still have elements left to merge.
+    pub fn active_sources(&self) -> usize {
+        match &self.double_ended {
+            Some(double_ended) => double_ended.len(),
+            None => self.tree.len(),
+        }
+    }
+
+    /// Returns `true` if the merge is exhausted.
+    pub fn is_empty(&self) -> bool {
+        self.active_sources() == 0
+    }
+
+    /// The element the comparator rates first among all the remaining elements,
+    /// which for a tournament from smallest to largest is the global minimum. It is
+    /// the next element to be yielded, so this is O(1). See
+    /// [`Tournament::max_element`] for the opposite extreme.
+    pub fn min_element(&self) -> Option<&T::Item> {
+        self.peek()
+    }
+
+    /// The element the next call to `next` returns, together with the runner up:
+    /// the least of the elements the other sources have buffered, which is the
+    /// child of the top of the heap that compares least. The element after the
+    /// next is either the runner up, or an element of the winning source that is
+    /// not pulled yet and can only be smaller, so the runner up is a cheap upper
+    /// bound on it, for example to find the gap between the two smallest heads.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tournament_kway::Tournament;
+    ///
+    /// let t = Tournament::from_iters_min([vec![1, 2], vec![5, 6], vec![4]].map(Vec::into_iter));
+    /// assert_eq!(t.peek_two(), (Some(&1), Some(&4)));
+    ///
+    /// let t = Tournament::from_iters_min([(1..3)]);
+    /// assert_eq!(t.peek_two(), (Some(&1), None));
+    /// ```
+    pub fn peek_two(&self) -> (Option<&T::Item>, Option<&T::Item>)
+    where
+        C: Comparator<T::Item>,
+    {
+        if let Some(double_ended) = &self.double_ended {
+            let runner_up = double_ended.runner_up(&self.comparator, &self.tie_break);
+            return (double_ended.peek(), runner_up);
+        }
+        let order = entry_order(&self.comparator, &self.tie_break);
+        let runner_up = self
+            .tree
+            .runner_up(order)
+            .and_then(|pos| self.tree.iter().nth(pos));
+        (self.peek(), runner_up.map(|entry| &entry.item))
+    }
+
+    /// The number of entries in the heap, one for every source that still has
+    /// elements left to merge.
+    pub fn heap_len(&self) -> usize {
+        self.active_sources()
+    }
+
+    /// The number of entries the heap has room for without reallocating, for
+    /// example as reserved by [`Tournament::with_capacity`].
+    pub fn heap_capacity(&self) -> usize {
+        match &self.double_ended {
+            Some(double_ended) => double_ended.capacity(),
+            None => self.tree.capacity(),
+        }
+    }
+
+    /// The sources of the tournament, for example to inspect the state of a
+    /// stuck merge when `T` exposes its own. Sources that were empty when added
+    /// are not kept, and exhausted ones are. The element each active source has
+    /// buffered in the tournament is not part of it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tournament_kway::Tournament;
+    ///
+    /// let mut t = Tournament::from_iters_min([vec![1, 3, 5], vec![], vec![2, 4]].map(Vec::into_iter));
+    /// assert_eq!(t.next(), Some(1));
+    /// let left = t.sources().iter().map(|s| s.as_slice()).collect::<Vec<_>>();
+    /// assert_eq!(left, [[5], [4]]);
+    /// ```
+    pub fn sources(&self) -> &[T] {
+        &self.results
+    }
+
+    /// The id the next source added to the tournament will get, as reported by
+    /// [`Tournament::inspect_source`]. Every source gets the next id when it is
+    /// added, including empty sources and those moved in by [`Tournament::merge`],
+    /// and ids are never reused, even after [`Tournament::retain_sources`] drops
+    /// sources. Only [`Tournament::clear`] starts them over, for a new merge.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tournament_kway::Tournament;
+    ///
+    /// let mut t = Tournament::from_iters_min([(1..3), (0..0)]);
+    /// let id = t.next_source_id();
+    /// t.push_iter(5..7);
+    /// let mut sources = Vec::new();
+    /// t.inspect_source(|source, _| sources.push(source)).for_each(drop);
+    /// assert_eq!((id, sources), (2, vec![0, 0, 2, 2]));
+    /// ```
+    pub fn next_source_id(&self) -> usize {
+        self.sources
+    }
+
+    /// Mutable access to the sources of the tournament, in the same order as
+    /// [`Tournament::sources`]. Whatever is done to a source, the elements it
+    /// yields next must stay sorted and must not be ordered before the element it
+    /// has buffered in the tournament, otherwise the output is no longer sorted.
+    pub fn sources_mut(&mut self) -> &mut [T] {
+        &mut self.results
+    }
+
+    /// Take back the sources of the tournament, for example to resume them after
+    /// a partial merge. Every source that is still active has already had its
+    /// next element pulled into the tournament, which is returned alongside it.
+    /// Sources that were empty when added are not returned.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the tournament was consumed from the back, as the sources then
+    /// have elements buffered at both ends. Use [`Tournament::into_parts`] instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tournament_kway::Tournament;
+    ///
+    /// let mut t = Tournament::from_iters_min([(1..3), (2..6)]);
+    /// assert_eq!(t.by_ref().take(3).collect::<Vec<_>>(), [1, 2, 2]);
+    ///
+    /// let rest = t
+    ///     .into_inner()
+    ///     .into_iter()
+    ///     .map(|(head, iter)| head.into_iter().chain(iter).collect::<Vec<_>>())
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(rest, [vec![], vec![3, 4, 5]]);
+    /// ```
+    pub fn into_inner(self) -> Vec<(Option<T::Item>, T)> {
+        assert!(
+            self.double_ended.is_none(),
+            "the tournament was consumed from the back, use `into_parts` instead"
+        );
+        self.into_parts()
+            .into_iter()
+            .map(|(head, iter, _)| (head, iter))
+            .collect()
+    }
+
+    /// Take back the sources of the tournament together with the elements they
+    /// have buffered in it: the next element at the front, and if the tournament
+    /// was consumed from the back, the next element at the back, which comes after
+    /// the rest of the source. When a single element is left, it is returned at one
+    /// of the two ends. Sources that were empty when added are not returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tournament_kway::Tournament;
+    ///
+    /// let mut t = Tournament::from_iters_min([vec![1, 3], vec![2, 4, 6]].map(Vec::into_iter));
+    /// assert_eq!(t.next_back(), Some(6));
+    ///
+    /// let rest = t
+    ///     .into_parts()
+    ///     .into_iter()
+    ///     .map(|(head, iter, tail)| head.into_iter().chain(iter).chain(tail).collect::<Vec<_>>())
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(rest, [vec![1, 3], vec![2, 4]]);
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn into_parts(self) -> Vec<(Option<T::Item>, T, Option<T::Item>)> {
+        if let Some(double_ended) = self.double_ended {
+            return double_ended
+                .into_ends()
+                .into_iter()
+                .zip(self.results)
+                .map(|((head, tail), iter)| (head, iter, tail))
+                .collect();
+        }
+
+        let mut heads = std::iter::repeat_with(|| None)
+            .take(self.results.len())
+            .collect::<Vec<_>>();
+        for TournamentEntry { item, index } in self.tree.into_vec() {
+            heads[index] = Some(item);
+        }
+
+        heads
+            .into_iter()
+            .zip(self.results)
+            .map(|(head, iter)| (head, iter, None))
+            .collect()
+    }
+
+    /// The element that the next call to `next` will return, without advancing.
+    pub(crate) fn peek(&self) -> Option<&T::Item> {
+        if let Some(double_ended) = &self.double_ended {
+            return double_ended.peek();
+        }
+        self.tree.peek().map(|entry| &entry.item)
+    }
+
+    /// The total number of sources, including empty and exhausted ones.
+    pub(crate) fn sources_len(&self) -> usize {
+        self.sources
+    }
+
+    /// The number of sources that were empty when added, which are not kept.
+    pub(crate) fn empty_sources(&self) -> usize {
+        self.empty
+    }
+
+    /// The index of the source of `slot` among all the sources added, including
+    /// empty ones.
+    pub(crate) fn source_id(&self, slot: usize) -> usize {
+        self.ids[slot]
+    }
+
+    /// The number of sources that were not empty when added. Entries of the tree
+    /// index into them.
+    pub(crate) fn slots_len(&self) -> usize {
+        self.results.len()
+    }
+
+    pub(crate) fn comparator(&self) -> &C {
+        &self.comparator
+    }
+}
+
+impl<T, C, const D: usize> Tournament<T, C, D>
+where
+    T: Iterator + Clone,
+    T::Item: Clone,
+    C: Clone,
+{
+    /// Take an in-memory snapshot of the tournament, cloning its sources, their
+    /// buffered elements and the comparator. The snapshot can be kept aside and
+    /// turned back into a tournament with [`Tournament::restore`], picking up the
+    /// merge where it was taken, for example to roll back speculative consumption.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tournament_kway::Tournament;
+    ///
+    /// let mut t = Tournament::from_iters_min([(1..4), (2..5)]);
+    /// assert_eq!(t.next(), Some(1));
+    /// let state = t.checkpoint();
+    /// assert_eq!(t.by_ref().take(3).collect::<Vec<_>>(), [2, 2, 3]);
+    ///
+    /// let t = Tournament::restore(state);
+    /// assert_eq!(t.collect::<Vec<_>>(), [2, 2, 3, 3, 4]);
+    /// ```
+    pub fn checkpoint(&self) -> TournamentState<T, C, D> {
+        TournamentState {
+            tournament: self.clone(),
+        }
+    }
+}
+
+impl<T, C, const D: usize> Tournament<T, C, D>
+where
+    T: Iterator,
+{
+    /// Turn a snapshot taken by [`Tournament::checkpoint`] back into a working
+    /// tournament, in the state it was in when the snapshot was taken.
+    pub fn restore(state: TournamentState<T, C, D>) -> Self {
+        state.tournament
+    }
+}
+
+/// A snapshot of a [`Tournament`], as taken by [`Tournament::checkpoint`]. It owns
+/// clones of the sources and their buffered elements, and can only be turned back
+/// into a tournament with [`Tournament::restore`].
+pub struct TournamentState<T, C, const D: usize = 2>
+where
+    T: Iterator,
+{
+    tournament: Tournament<T, C, D>,
+}
+
+impl<T, C, const D: usize> Clone for TournamentState<T, C, D>
+where
+    T: Iterator + Clone,
+    T::Item: Clone,
+    C: Clone,
+{
+    fn clone(&self) -> Self {
+        TournamentState {
+            tournament: self.tournament.clone(),
+        }
+    }
+}
+
+impl<T, C, const D: usize> std::fmt::Debug for TournamentState<T, C, D>
+where
+    T: Iterator + std::fmt::Debug,
+    T::Item: std::fmt::Debug,
+    C: std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TournamentState")
+            .field("tournament", &self.tournament)
+            .finish()
+    }
+}
+
+/// Collect sources that are sorted from smallest to largest into a tournament,
+/// as with [`Tournament::from_iters_min`].
+///
+/// # Examples
+///
+/// ```
+/// use tournament_kway::Tournament;
+///
+/// let t: Tournament<_, _> = [(1..4), (2..5)].into_iter().collect();
+/// assert_eq!(t.collect::<Vec<_>>(), [1, 2, 2, 3, 3, 4]);
+/// ```
+impl<T> FromIterator<T> for Tournament<T, MinComparator<T::Item>>
+where
+    T: Iterator,
+    T::Item: Ord,
+{
+    fn from_iter<I: IntoIterator<Item = T>>(iters: I) -> Self {
+        Tournament::from_iters_min(iters)
+    }
+}
+
+/// Add sources to the tournament, as with [`Tournament::push_iter`]. The same
+/// ordering caveat applies: the new sources must not yield elements that are
+/// ordered before elements that were already yielded.
+impl<T, C, const D: usize> Extend<T> for Tournament<T, C, D>
+where
+    T: Iterator,
+    C: Comparator<T::Item>,
+{
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iters: I) {
+        let iters = iters.into_iter();
+        self.reserve(iters.size_hint().0);
+        for iter in iters {
+            self.push_iter(iter);
+        }
+    }
+}
+
+impl<T, C, const D: usize> Iterator for Tournament<T, C, D>
+where
+    T: Iterator,
+    C: Comparator<T::Item>,
+{
+    type Item = T::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_with_index().map(|(_, item)| item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if let Some(double_ended) = &self.double_ended {
+            return double_ended.size_hint(&self.results);
+        }
+
+        self.tree
+            .iter()
+            .fold((0, Some(0)), |(lower, upper), entry| {
+                let (l, u) = self.results[entry.index].size_hint();
+                (
+                    lower.saturating_add(l).saturating_add(1),
+                    upper
+                        .zip(u)
+                        .and_then(|(a, b)| a.checked_add(b))
+                        .and_then(|u| u.checked_add(1)),
+                )
+            })
+    }
+
+    /// Skipping is not cheaper than yielding: every skipped element still has to
+    /// be pulled through the heap, so this is O(n log k) for k sources.
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        for _ in 0..n {
+            self.next_with_index()?;
+        }
+        self.next()
+    }
+
+    /// The last merged element is the greatest of the last elements of the
+    /// sources, so every source is drained with its own [`Iterator::last`] instead
+    /// of pulling its elements through the heap. This is still O(n) for n remaining
+    /// elements, unlike on a slice, but only k comparisons are made.
+    fn last(self) -> Option<Self::Item> {
+        let comparator = self.comparator;
+        let tie_break = self.tie_break;
+        if let Some(double_ended) = self.double_ended {
+            return double_ended.last(self.results, &comparator, &tie_break);
+        }
+
+        let mut results = self.results.into_iter().map(Some).collect::<Vec<_>>();
+
+        self.tree
+            .into_vec()
+            .into_iter()
+            .map(|TournamentEntry { item, index }| TournamentEntry {
+                item: results[index]
+                    .take()
+                    .and_then(Iterator::last)
+                    .unwrap_or(item),
+                index,
+            })
+            .max_by(entry_order(&comparator, &tie_break))
+            .map(|entry| entry.item)
+    }
+}
+
+/// Yield the largest remaining elements from the back, for sources that are
+/// [`DoubleEndedIterator`]s. The front and the back meet in the middle, every
+/// element being yielded exactly once.
+///
+/// The first call to `next_back` switches the tournament to tracking both ends,
+/// which pulls one more element from every source, and keeps a second heap. The
+/// extra memory is about two elements and three indices per source. Once a
+/// source is exhausted at one end while it still buffers an element at the other,
+/// it is removed from the other heap in O(k) for k sources.
+///
+/// This also makes [`Iterator::rev`] available, which turns an ascending merge
+/// into a descending one without rebuilding it or reversing the sources.
+///
+/// # Examples
+///
+/// ```
+/// use tournament_kway::Tournament;
+///
+/// let mut t = Tournament::from_iters_min([(1..4), (2..6)]);
+/// assert_eq!(t.next_back(), Some(5));
+/// assert_eq!(t.next(), Some(1));
+/// assert_eq!(t.next_back(), Some(4));
+/// assert_eq!(t.collect::<Vec<_>>(), [2, 2, 3, 3]);
+///
+/// let t = Tournament::from_iters_min([(1..4), (2..6)]);
+/// assert_eq!(t.rev().collect::<Vec<_>>(), [5, 4, 3, 3, 2, 2, 1]);
+/// ```
+impl<T, C, const D: usize> DoubleEndedIterator for Tournament<T, C, D>
+where
+    T: DoubleEndedIterator,
+    C: Comparator<T::Item>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.poison();
+        let comparator = &self.comparator;
+        let tie_break = &self.tie_break;
+        let double_ended = track_both_ends(
+            &mut self.double_ended,
+            &mut self.tree,
+            self.results.len(),
+            comparator,
+            tie_break,
+        );
+
+        let next = double_ended.next_back(&mut self.results, comparator, tie_break);
+        self.poisoned = false;
+        next.map(|(_, item)| item)
+    }
+}
+
+/// Sources that know their exact length give the number of elements left in O(k)
+/// for k sources, as the sum of their lengths and the buffered elements, without
+/// pulling anything from them, unlike [`Iterator::count`], which drains the merge.
+impl<T, C, const D: usize> ExactSizeIterator for Tournament<T, C, D>
+where
+    T: ExactSizeIterator,
+    C: Comparator<T::Item>,
+{
+}
+
+/// An entry into the inner tree, holding the current element of a source
+#[derive(Clone, Debug)]
+struct TournamentEntry<I> {
+    item: I,
+    index: usize,
+}
+
+/// Build a heap of the entries whose slots are kept by `remap`, renumbered.
+fn remap_tree<I, C, const D: usize>(
+    entries: impl IntoIterator<Item = TournamentEntry<I>>,
+    remap: &[Option<usize>],
+    comparator: &C,
+    tie_break: &TieBreak,
+) -> DaryHeap<TournamentEntry<I>, D>
+where
+    C: Comparator<I>,
+{
+    let entries = entries.into_iter();
+    let mut tree = DaryHeap::with_capacity(entries.size_hint().0);
+    for TournamentEntry { item, index } in entries {
+        if let Some(index) = remap[index] {
+            tree.push(
+                TournamentEntry { item, index },
+                entry_order(comparator, tie_break),
+            );
+        }
+    }
+    tree
+}
+
+/// The order of the entries in the tree, as decided by the comparator of the
+/// tournament, which is shared by all the entries, and its tie break.
+fn entry_order<'a, I, C>(
+    comparator: &'a C,
+    tie_break: &'a TieBreak,
+) -> impl Fn(&TournamentEntry<I>, &TournamentEntry<I>) -> Ordering + 'a
+where
+    C: Comparator<I>,
+{
+    move |a, b| {
+        comparator
+            .cmp(&a.item, &b.item)
+            .then_with(|| tie_break.cmp(a.index, b.index))
+    }
+}
+
+/// Switch a tournament over `slots` sources to tracking both ends, if it does not
+/// already, moving the entries of its tree over.
+fn track_both_ends<'a, I, C, const D: usize>(
+    double_ended: &'a mut Option<Box<DoubleEnded<I, D>>>,
+    tree: &mut DaryHeap<TournamentEntry<I>, D>,
+    slots: usize,
+    comparator: &C,
+    tie_break: &TieBreak,
+) -> &'a mut DoubleEnded<I, D>
+where
+    C: Comparator<I>,
+{
+    double_ended.get_or_insert_with(|| {
+        let tree = std::mem::replace(tree, DaryHeap::with_capacity(0));
+        Box::new(DoubleEnded::new(
+            tree.into_vec()
+                .into_iter()
+                .map(|TournamentEntry { item, index }| (index, item)),
+            slots,
+            comparator,
+            tie_break,
+        ))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{
+        distributions::{Alphanumeric, DistString},
+        Rng,
+    };
+
+    use std::cmp::{Ordering, Reverse};
+    use std::collections::{BTreeSet, VecDeque};
+    use std::ops::ControlFlow;
+
+    use crate::{by_key, Comparator, MinComparator, Tournament, UnsortedError};
+
+    #[test]
+    fn test_min() {
+        let mut rng = rand::thread_rng();
+
+        let vecs = (1..100)
+            .map(|_| {
+                (1..200)
+                    .map(|_| Alphanumeric.sample_string(&mut rng, 32))
+                    .collect::<Vec<_>>()
+            })
+            .map(|mut v| {
+                v.sort();
+                v
+            })
+            .collect::<Vec<_>>();
+
+        let tournament_result =
+            Tournament::from_iters_min(vecs.iter().map(|v| v.iter())).collect::<Vec<_>>();
+
+        let mut sort_result = vecs.iter().flatten().collect::<Vec<_>>();
+        sort_result.sort();
+
+        assert_eq!(tournament_result, sort_result);
+    }
+
+    #[test]
+    fn test_max() {
+        let mut rng = rand::thread_rng();
 
         let vecs = (1..100)
             .map(|_| {
-                (1..200)
-                    .map(|_| Alphanumeric.sample_string(&mut rng, 32))
-                    .collect::<Vec<_>>()
-            })
-            .map(|mut v| {
+                (1..200)
+                    .map(|_| Alphanumeric.sample_string(&mut rng, 32))
+                    .collect::<Vec<_>>()
+            })
+            .map(|mut v| {
+                v.sort_by(|a, b| b.cmp(a));
+                v
+            })
+            .collect::<Vec<_>>();
+
+        let tournament_result =
+            Tournament::from_iters_max(vecs.iter().map(|v| v.iter())).collect::<Vec<_>>();
+
+        let mut sort_result = vecs.iter().flatten().collect::<Vec<_>>();
+        sort_result.sort_by(|a, b| b.cmp(a));
+
+        assert_eq!(tournament_result, sort_result);
+    }
+
+    #[test]
+    fn test_arity() {
+        let mut rng = rand::thread_rng();
+
+        let vecs = (0..1000)
+            .map(|_| {
+                let mut v = (0..rng.gen_range(0..50))
+                    .map(|_| rng.gen_range(0..10000))
+                    .collect::<Vec<u32>>();
+                v.sort();
+                v
+            })
+            .collect::<Vec<_>>();
+
+        let binary = Tournament::from_iters_min(vecs.iter().map(|v| v.iter())).collect::<Vec<_>>();
+        let ternary = Tournament::from_iters_min(vecs.iter().map(|v| v.iter()))
+            .with_arity::<3>()
+            .collect::<Vec<_>>();
+        let quaternary = Tournament::from_iters_min(vecs.iter().map(|v| v.iter()))
+            .with_arity::<4>()
+            .collect::<Vec<_>>();
+        let octonary = Tournament::from_iters_min(vecs.iter().map(|v| v.iter()))
+            .with_arity::<8>()
+            .collect::<Vec<_>>();
+
+        let mut sort_result = vecs.iter().flatten().collect::<Vec<_>>();
+        sort_result.sort();
+
+        assert_eq!(binary, sort_result);
+        assert_eq!(ternary, sort_result);
+        assert_eq!(quaternary, sort_result);
+        assert_eq!(octonary, sort_result);
+    }
+
+    #[test]
+    fn test_active_sources() {
+        let mut tournament = Tournament::from_iters_min([1..3, 0..0, 2..6]);
+        assert_eq!(tournament.active_sources(), 2);
+
+        assert_eq!(tournament.by_ref().take(3).collect::<Vec<_>>(), [1, 2, 2]);
+        assert_eq!(tournament.active_sources(), 1);
+        assert!(!tournament.is_empty());
+
+        assert_eq!(tournament.by_ref().collect::<Vec<_>>(), [3, 4, 5]);
+        assert_eq!(tournament.active_sources(), 0);
+        assert!(tournament.is_empty());
+    }
+
+    #[test]
+    fn test_fold() {
+        let mut rng = rand::thread_rng();
+
+        let vecs = (0..100)
+            .map(|_| {
+                let mut v = (0..rng.gen_range(0..100))
+                    .map(|_| rng.gen_range(0..1000))
+                    .collect::<Vec<u32>>();
                 v.sort();
                 v
             })
             .collect::<Vec<_>>();
 
-        let tournament_result =
-            Tournament::from_iters_min(vecs.iter().map(|v| v.iter())).collect::<Vec<_>>();
+        let tournament = Tournament::from_iters_min(vecs.iter().map(|v| v.iter()));
+        let mut next_result = Vec::new();
+        for item in tournament {
+            next_result.push(item);
+        }
 
-        let mut sort_result = vecs.iter().flatten().collect::<Vec<_>>();
-        sort_result.sort();
+        let tournament = Tournament::from_iters_min(vecs.iter().map(|v| v.iter()));
+        let fold_result = tournament.fold(Vec::new(), |mut acc, item| {
+            acc.push(item);
+            acc
+        });
 
-        assert_eq!(tournament_result, sort_result);
+        assert_eq!(fold_result, next_result);
     }
 
     #[test]
-    fn test_max() {
+    fn test_sum() {
         let mut rng = rand::thread_rng();
-
-        let vecs = (1..100)
+        let sources = (0..10)
             .map(|_| {
-                (1..200)
-                    .map(|_| Alphanumeric.sample_string(&mut rng, 32))
-                    .collect::<Vec<_>>()
-            })
-            .map(|mut v| {
-                v.sort_by(|a, b| b.cmp(a));
+                let mut v = (0..100)
+                    .map(|_| rng.gen::<f64>() * 1e10f64.powi(rng.gen_range(-3..3)))
+                    .collect::<Vec<_>>();
+                v.sort_by(f64::total_cmp);
                 v
             })
             .collect::<Vec<_>>();
+        let mut sorted = sources.concat();
+        sorted.sort_by(f64::total_cmp);
 
-        let tournament_result =
-            Tournament::from_iters_max(vecs.iter().map(|v| v.iter())).collect::<Vec<_>>();
+        let t = Tournament::from_iters_min_f64(sources.iter().map(|v| v.iter().copied()));
+        assert_eq!(
+            t.sum::<f64>().to_bits(),
+            sorted.iter().sum::<f64>().to_bits()
+        );
+    }
 
-        let mut sort_result = vecs.iter().flatten().collect::<Vec<_>>();
-        sort_result.sort_by(|a, b| b.cmp(a));
+    #[test]
+    fn test_nth() {
+        let sources = [vec![1, 4, 7], vec![2, 5, 8], vec![3, 6, 9]];
+        let merged =
+            Tournament::from_iters_min(sources.clone().map(Vec::into_iter)).collect::<Vec<_>>();
 
-        assert_eq!(tournament_result, sort_result);
+        let mut tournament = Tournament::from_iters_min(sources.map(Vec::into_iter));
+        assert_eq!(tournament.nth(3), Some(merged[3]));
+        assert_eq!(tournament.nth(1), Some(merged[5]));
+        assert_eq!(tournament.nth(2), Some(merged[8]));
+        assert_eq!(tournament.nth(1), None);
+        assert!(tournament.is_empty());
+    }
+
+    #[test]
+    fn test_stable() {
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..200 {
+            // Few distinct keys, so most elements draw with those of other sources
+            let sources = (0..rng.gen_range(0..10))
+                .map(|source| {
+                    let mut keys = (0..rng.gen_range(0..30))
+                        .map(|_| rng.gen_range(0..4))
+                        .collect::<Vec<u32>>();
+                    keys.sort();
+                    keys.into_iter()
+                        .enumerate()
+                        .map(|(pos, key)| (key, source, pos))
+                        .collect::<Vec<_>>()
+                })
+                .collect::<Vec<_>>();
+
+            let mut expected = sources.concat();
+            expected.sort_by_key(|&(key, _, _)| key);
+            let mut expected = VecDeque::from(expected);
+
+            let mut t = Tournament::from_iters_stable(
+                sources.into_iter().map(Vec::into_iter),
+                by_key(|&(key, _, _): &(u32, usize, usize)| key),
+            );
+            if rng.gen() {
+                t = t.with_arity::<3>().with_arity::<2>();
+            }
+            let back = rng.gen_bool(0.5);
+            while !expected.is_empty() {
+                if back && rng.gen() {
+                    assert_eq!(t.next_back(), expected.pop_back());
+                } else {
+                    assert_eq!(t.next(), expected.pop_front());
+                }
+            }
+            assert_eq!(t.next(), None);
+        }
+    }
+
+    #[test]
+    fn test_weighted() {
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..200 {
+            let sources = (0..rng.gen_range(0..10))
+                .map(|source| {
+                    let mut keys = (0..rng.gen_range(0..30))
+                        .map(|_| rng.gen_range(0..4))
+                        .collect::<Vec<u32>>();
+                    keys.sort();
+                    let source = keys
+                        .into_iter()
+                        .enumerate()
+                        .map(|(pos, key)| (key, source, pos))
+                        .collect::<Vec<_>>();
+                    (source, rng.gen_range(0..3))
+                })
+                .collect::<Vec<_>>();
+
+            let mut expected = sources
+                .iter()
+                .flat_map(|(source, weight)| source.iter().map(move |&e| (e, *weight)))
+                .collect::<Vec<_>>();
+            expected.sort_by_key(|&((key, source, _), weight)| (key, Reverse(weight), source));
+            let mut expected = expected
+                .into_iter()
+                .map(|(e, _)| e)
+                .collect::<VecDeque<_>>();
+
+            let mut t = Tournament::from_weighted_iters(
+                sources
+                    .into_iter()
+                    .map(|(source, weight)| (source.into_iter(), weight)),
+                by_key(|&(key, _, _): &(u32, usize, usize)| key),
+            );
+            if rng.gen() {
+                // Dropping sources keeps the weights of the others
+                let dropped = rng.gen_range(0..2);
+                t.retain_sources(|&(key, _, _)| key >= dropped);
+                expected.retain(|&(_, source, _)| t.ids.contains(&source));
+            }
+            let back = rng.gen_bool(0.5);
+            while !expected.is_empty() {
+                if back && rng.gen() {
+                    assert_eq!(t.next_back(), expected.pop_back());
+                } else {
+                    assert_eq!(t.next(), expected.pop_front());
+                }
+            }
+            assert_eq!(t.next(), None);
+        }
+
+        // Merged sources without a weight weigh 0
+        let order = by_key(|&(key, _): &(u32, char)| key);
+        let t = Tournament::from_weighted_iters([(vec![(1, 'a')].into_iter(), 0)], order);
+        let other =
+            Tournament::from_iters([vec![(1, 'b')], vec![(1, 'c')]].map(Vec::into_iter), order);
+        let mut t = t.merge(other);
+        t.push_iter(vec![(1, 'd')].into_iter());
+        assert_eq!(t.map(|(_, c)| c).collect::<String>(), "abcd");
+    }
+
+    #[test]
+    fn test_stable_last() {
+        let sources = [vec![(1, 'a'), (2, 'b')], vec![(2, 'c')], vec![(0, 'd')]];
+        let comparator = by_key(|&(key, _): &(u32, char)| key);
+        let t = Tournament::from_iters_stable(sources.map(Vec::into_iter), comparator);
+        assert_eq!(t.clone().last(), Some((2, 'c')));
+
+        let mut t = t;
+        assert_eq!(t.next_back(), Some((2, 'c')));
+        assert_eq!(t.last(), Some((2, 'b')));
+    }
+
+    #[test]
+    fn test_select_kth() {
+        let sources = [vec![1, 4, 7], vec![2, 5, 8], vec![], vec![3, 6, 9]];
+        for k in 0..9 {
+            let kth = Tournament::select_kth(
+                sources.clone().map(Vec::into_iter),
+                k,
+                MinComparator::default(),
+            );
+            assert_eq!(kth, Some(k + 1));
+        }
+        let kth = Tournament::select_kth(sources.map(Vec::into_iter), 9, MinComparator::default());
+        assert_eq!(kth, None);
+    }
+
+    #[test]
+    fn test_median() {
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..100 {
+            let sources = (0..rng.gen_range(0..6))
+                .map(|_| {
+                    let mut v = (0..rng.gen_range(0..20))
+                        .map(|_| rng.gen_range(0..50))
+                        .collect::<Vec<u32>>();
+                    v.sort();
+                    v
+                })
+                .collect::<Vec<_>>();
+            let mut all = sources.concat();
+            all.sort();
+            let expected = all.len().checked_sub(1).map(|last| all[last / 2]);
+
+            let median = Tournament::median(
+                sources.into_iter().map(Vec::into_iter),
+                MinComparator::default(),
+            );
+            assert_eq!(median, expected);
+        }
+    }
+
+    #[test]
+    fn test_last() {
+        let sources = [vec![1, 4, 7], vec![2, 5, 9], vec![3], vec![]];
+        assert_eq!(
+            Tournament::from_iters_min(sources.clone().map(Vec::into_iter)).last(),
+            Some(9)
+        );
+
+        let mut tournament = Tournament::from_iters_min(sources.map(Vec::into_iter));
+        assert_eq!(tournament.by_ref().take(3).count(), 3);
+        assert_eq!(tournament.last(), Some(9));
+
+        let tournament = Tournament::from_iters_max([(0..5).rev(), (3..8).rev()]);
+        assert_eq!(tournament.last(), Some(0));
+
+        assert_eq!(Tournament::from_iters_min([0..0, 0..0]).last(), None);
+    }
+
+    #[test]
+    fn test_double_ended() {
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..100 {
+            let vecs = (0..rng.gen_range(0..10))
+                .map(|_| {
+                    let mut v = (0..rng.gen_range(0..10))
+                        .map(|_| rng.gen_range(0..20))
+                        .collect::<Vec<u32>>();
+                    v.sort();
+                    v
+                })
+                .collect::<Vec<_>>();
+
+            let mut expected = vecs.iter().flatten().copied().collect::<VecDeque<_>>();
+            expected.make_contiguous().sort();
+
+            let mut tournament = Tournament::from_iters_min(vecs.into_iter().map(Vec::into_iter));
+            while !expected.is_empty() {
+                assert_eq!(tournament.active_sources() == 0, expected.is_empty());
+                if rng.gen() {
+                    assert_eq!(tournament.next(), expected.pop_front());
+                } else {
+                    assert_eq!(tournament.next_back(), expected.pop_back());
+                }
+            }
+            assert_eq!(tournament.next(), None);
+            assert_eq!(tournament.next_back(), None);
+            assert!(tournament.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_into_parts() {
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..100 {
+            let vecs = (0..rng.gen_range(0..10))
+                .map(|_| {
+                    let mut v = (0..rng.gen_range(0..10))
+                        .map(|_| rng.gen_range(0..20))
+                        .collect::<Vec<u32>>();
+                    v.sort();
+                    v
+                })
+                .collect::<Vec<_>>();
+
+            let mut tournament = Tournament::from_iters_min(vecs.iter().map(|v| v.iter()));
+            let mut yielded: Vec<&u32> = Vec::new();
+            for _ in 0..rng.gen_range(0..10) {
+                let next = if rng.gen() {
+                    tournament.next()
+                } else {
+                    tournament.next_back()
+                };
+                yielded.extend(next);
+            }
+
+            // Every source is returned in order, and no element goes missing
+            for (head, iter, tail) in tournament.into_parts() {
+                let rest = head.into_iter().chain(iter).chain(tail).collect::<Vec<_>>();
+                assert!(rest.windows(2).all(|w| w[0] <= w[1]));
+                yielded.extend(rest);
+            }
+            yielded.sort();
+            let mut expected = vecs.iter().flatten().collect::<Vec<_>>();
+            expected.sort();
+            assert_eq!(yielded, expected);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "use `into_parts` instead")]
+    fn test_into_inner_after_next_back() {
+        let mut tournament =
+            Tournament::from_iters_min([vec![1, 3], vec![2, 4]].map(Vec::into_iter));
+        assert_eq!(tournament.next_back(), Some(4));
+        tournament.into_inner();
+    }
+
+    #[test]
+    fn test_double_ended_push_iter() {
+        let mut tournament = Tournament::from_iters_min([vec![1, 4, 9]].map(Vec::into_iter));
+        assert_eq!(tournament.next_back(), Some(9));
+
+        tournament.push_iter(vec![2, 3, 10].into_iter());
+        assert_eq!(tournament.clone().last(), Some(10));
+        assert_eq!(tournament.next_back(), Some(10));
+        assert_eq!(tournament.next(), Some(1));
+        assert_eq!(tournament.with_arity::<3>().collect::<Vec<_>>(), [2, 3, 4]);
+    }
+
+    #[test]
+    fn test_checkpoint() {
+        let mut t =
+            Tournament::from_iters_min([vec![1, 4, 7], vec![], vec![2, 5, 8]].map(Vec::into_iter));
+        assert_eq!(t.next(), Some(1));
+        assert_eq!(t.next_back(), Some(8));
+        let state = t.checkpoint();
+
+        // Consuming the tournament leaves the snapshot untouched
+        assert_eq!(t.by_ref().collect::<Vec<_>>(), [2, 4, 5, 7]);
+        let mut t = Tournament::restore(state.clone());
+        assert_eq!(t.next_back(), Some(7));
+        assert_eq!(t.by_ref().collect::<Vec<_>>(), [2, 4, 5]);
+
+        let t = Tournament::restore(state);
+        assert_eq!(t.active_sources(), 2);
+        assert_eq!(t.collect::<Vec<_>>(), [2, 4, 5, 7]);
+    }
+
+    #[test]
+    fn test_merge() {
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..100 {
+            let [a, b] = [0, 1].map(|_| {
+                (0..rng.gen_range(0..5))
+                    .map(|_| {
+                        let mut v = (0..rng.gen_range(0..20))
+                            .map(|_| rng.gen_range(0..50))
+                            .collect::<Vec<u32>>();
+                        v.sort();
+                        v
+                    })
+                    .collect::<Vec<_>>()
+            });
+            let mut expected = a.concat();
+            expected.extend(b.concat());
+            expected.sort();
+            let mut expected = VecDeque::from(expected);
+
+            // Either side may be consumed from both ends before the merge
+            let mut take = |t: &mut Tournament<std::vec::IntoIter<u32>, _>| {
+                if rng.gen() {
+                    let front = rng.gen_range(0..3);
+                    let back = rng.gen_range(0..3);
+                    let front = t.by_ref().take(front).collect::<Vec<_>>();
+                    let back = t.by_ref().rev().take(back).collect::<Vec<_>>();
+                    for i in front {
+                        expected.remove(expected.iter().position(|&e| e == i).unwrap());
+                    }
+                    for i in back {
+                        expected.remove(expected.iter().rposition(|&e| e == i).unwrap());
+                    }
+                }
+            };
+            let mut a = Tournament::from_iters_min(a.into_iter().map(Vec::into_iter));
+            let mut b = Tournament::from_iters_min(b.into_iter().map(Vec::into_iter));
+            take(&mut a);
+            take(&mut b);
+
+            let merged = a.merge(b);
+            assert_eq!(merged.collect::<Vec<_>>(), Vec::from(expected));
+        }
+    }
+
+    #[test]
+    fn test_drain_remaining() {
+        let mut tournament =
+            Tournament::from_iters_min([vec![1, 4, 6], vec![2, 3]].map(Vec::into_iter));
+        assert_eq!(tournament.next(), Some(1));
+        assert_eq!(tournament.next(), Some(2));
+
+        assert_eq!(tournament.drain_remaining(), [3, 4, 6]);
+        assert!(tournament.is_empty());
+        assert_eq!(tournament.drain_remaining(), []);
+
+        tournament.extend([vec![7, 9], vec![8]].map(Vec::into_iter));
+        assert_eq!(tournament.drain_remaining(), [7, 8, 9]);
+    }
+
+    #[test]
+    fn test_fold_while() {
+        let mut t = Tournament::from_iters_min([0..4, 2..6, 10..12]);
+        let mut seen = Vec::new();
+        let flow = t.fold_while(0, |count, i| {
+            seen.push(i);
+            if count == 2 {
+                ControlFlow::Break(i)
+            } else {
+                ControlFlow::Continue(count + 1)
+            }
+        });
+
+        assert_eq!(flow, ControlFlow::Break(2));
+        assert_eq!(seen, [0, 1, 2]);
+        assert_eq!(t.active_sources(), 3);
+        assert_eq!(t.collect::<Vec<_>>(), [2, 3, 3, 4, 5, 10, 11]);
+    }
+
+    #[test]
+    fn test_collect_into() {
+        let mut set = BTreeSet::from([0, 2]);
+        let t = Tournament::from_iters_min([vec![1, 2, 5], vec![2, 3, 5]].map(Vec::into_iter));
+        t.collect_into(&mut set);
+        assert_eq!(set.into_iter().collect::<Vec<_>>(), [0, 1, 2, 3, 5]);
+    }
+
+    #[test]
+    fn test_retain_sources() {
+        let sources = [
+            vec![1, 2, 8],
+            vec![],
+            vec![2, 3, 9],
+            vec![3, 5],
+            vec![4, 7, 10],
+        ];
+
+        let mut t = Tournament::from_iters_min(sources.clone().map(Vec::into_iter));
+        t.retain_sources(|head| head % 2 == 0);
+        assert_eq!(t.active_sources(), 2);
+        assert_eq!(t.sources().len(), 2);
+        let mut seen = Vec::new();
+        let merged = t
+            .inspect_source(|source, _| seen.push(source))
+            .collect::<Vec<_>>();
+        assert_eq!(merged, [2, 3, 4, 7, 9, 10]);
+        // The remaining sources keep their indices among all the sources added
+        assert_eq!(seen, [2, 2, 4, 4, 2, 4]);
+
+        let mut t = Tournament::from_iters_min(sources.map(Vec::into_iter));
+        assert_eq!(t.next(), Some(1));
+        assert_eq!(t.next_back(), Some(10));
+        assert_eq!(t.next_back(), Some(9));
+        t.retain_sources(|head| head % 2 == 0);
+        assert_eq!(t.active_sources(), 3);
+        assert_eq!(t.next_back(), Some(8));
+        assert_eq!(t.collect::<Vec<_>>(), [2, 2, 3, 4, 7]);
+    }
+
+    #[test]
+    fn test_clone_active() {
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..100 {
+            let sources = (0..rng.gen_range(0..10))
+                .map(|source| {
+                    let mut keys = (0..rng.gen_range(0..20))
+                        .map(|_| rng.gen_range(0..10))
+                        .collect::<Vec<u32>>();
+                    keys.sort();
+                    let source = keys
+                        .into_iter()
+                        .map(|key| (key, source))
+                        .collect::<Vec<_>>();
+                    (source.into_iter(), rng.gen_range(0..3))
+                })
+                .collect::<Vec<_>>();
+
+            let mut t =
+                Tournament::from_weighted_iters(sources, by_key(|&(key, _): &(u32, usize)| key));
+            for _ in 0..rng.gen_range(0..30) {
+                if rng.gen() {
+                    t.next();
+                } else {
+                    t.next_back();
+                }
+            }
+
+            let mut fork = t.clone_active();
+            assert_eq!(fork.sources().len(), fork.active_sources());
+            assert_eq!(fork.active_sources(), t.active_sources());
+            while let Some(item) = t.next() {
+                let (slot, forked) = fork.next_with_index().unwrap();
+                assert_eq!(forked, item);
+                // The sources keep their indices among all the sources added
+                assert_eq!(fork.source_id(slot), forked.1);
+                if rng.gen() {
+                    assert_eq!(fork.next_back(), t.next_back());
+                }
+            }
+            assert_eq!(fork.next(), None);
+        }
+    }
+
+    #[test]
+    fn test_source_ids() {
+        let mut t = Tournament::with_capacity(4, MinComparator::default());
+        let mut ids = Vec::new();
+        for source in [vec![1, 4], vec![2, 5], vec![3, 6]] {
+            ids.push(t.next_source_id());
+            t.push_iter(source.into_iter());
+        }
+        assert_eq!(ids, [0, 1, 2]);
+
+        // Drop the middle source, whose id is not handed out again
+        t.retain_sources(|&head| head != 2);
+        assert_eq!(t.next_source_id(), 3);
+        t.push_iter(vec![0, 7].into_iter());
+
+        let mut seen = Vec::new();
+        let merged = t
+            .inspect_source(|source, &item| seen.push((source, item)))
+            .collect::<Vec<_>>();
+        assert_eq!(merged, [0, 1, 3, 4, 6, 7]);
+        assert_eq!(seen, [(3, 0), (0, 1), (2, 3), (0, 4), (2, 6), (3, 7)]);
+    }
+
+    #[test]
+    fn test_count() {
+        let mut t = Tournament::from_iters_min([0..5, 0..0, 3..10]);
+        assert_eq!(t.size_hint(), (12, Some(12)));
+        t.nth(3);
+        assert_eq!(t.len(), 8);
+        assert_eq!(t.clone().count(), 8);
+        t.next_back();
+        assert_eq!(t.len(), 7);
+        assert_eq!(t.clone().count(), 7);
+        assert_eq!(t.collect::<Vec<_>>(), [3, 4, 4, 5, 6, 7, 8]);
+
+        // Sources of unknown length are drained
+        let t = Tournament::from_iters_min([2, 3].map(|n| (0..10).filter(move |i| i % n == 0)));
+        assert_eq!(t.size_hint(), (2, Some(20)));
+        assert_eq!(t.count(), 9);
+
+        // A wrong size hint does not change the count
+        struct Misreported(std::ops::Range<u32>);
+        impl Iterator for Misreported {
+            type Item = u32;
+            fn next(&mut self) -> Option<u32> {
+                self.0.next()
+            }
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                (0, Some(0))
+            }
+        }
+        let t = Tournament::from_iters_min([Misreported(0..4), Misreported(0..5)]);
+        assert_eq!(t.count(), 9);
+    }
+
+    #[test]
+    fn test_clear_keeps_capacity() {
+        let vecs = (0..100).map(|i| vec![i, i + 1]).collect::<Vec<_>>();
+        let mut tournament = Tournament::from_iters_min(vecs.iter().map(|v| v.iter()));
+        assert_eq!(tournament.next(), Some(&0));
+
+        tournament.clear();
+        assert!(tournament.is_empty());
+        assert_eq!(tournament.sources_len(), 0);
+        assert_eq!(tournament.tree.capacity(), 100);
+        assert_eq!(tournament.results.capacity(), 100);
+
+        tournament.extend(vecs[..10].iter().map(|v| v.iter()));
+        assert_eq!(tournament.tree.capacity(), 100);
+        assert_eq!(tournament.count(), 20);
+    }
+
+    #[test]
+    fn test_reserve() {
+        let mut tournament = Tournament::from_iters_min([vec![1, 2]].map(Vec::into_iter));
+        tournament.reserve(100);
+        assert!(tournament.heap_capacity() >= 101);
+        assert!(tournament.results.capacity() >= 101);
+        let capacity = tournament.heap_capacity();
+
+        tournament.extend((0..100).map(|i| vec![i].into_iter()));
+        assert_eq!(tournament.heap_capacity(), capacity);
+        assert_eq!(tournament.heap_len(), 101);
+
+        let mut tournament =
+            Tournament::<std::ops::Range<u32>, _>::with_capacity(0, MinComparator::default());
+        tournament.reserve(4);
+        #[cfg(not(feature = "smallvec"))]
+        assert!(tournament.heap_capacity() >= 4);
+        // Fits in the inline buffer
+        #[cfg(feature = "smallvec")]
+        assert!(!tournament.results.spilled());
+    }
+
+    #[test]
+    fn test_peek_two() {
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..100 {
+            let sources = (0..rng.gen_range(0..8))
+                .map(|_| {
+                    let mut v = (0..rng.gen_range(0..5))
+                        .map(|_| rng.gen_range(0..20))
+                        .collect::<Vec<u32>>();
+                    v.sort();
+                    v
+                })
+                .collect::<Vec<_>>();
+            let mut tournament = Tournament::from_iters_min(sources.iter().map(|v| v.iter()));
+            if rng.gen() {
+                tournament.next_back();
+            }
+
+            while let (Some(first), runner_up) = tournament.peek_two() {
+                let first = *first;
+                let runner_up = runner_up.copied();
+                assert!(runner_up.is_none_or(|r| first <= r));
+                let next = tournament.next();
+                assert_eq!(next, Some(first));
+                // The runner up bounds the element after
+                if let (Some(after), Some(runner_up)) = (tournament.peek_two().0, runner_up) {
+                    assert!(*after <= runner_up);
+                }
+            }
+            assert_eq!(tournament.peek_two(), (None, None));
+        }
+    }
+
+    #[test]
+    fn test_from_iters_by_ref() {
+        let mut sources = [vec![1, 4, 7], vec![], vec![2, 3, 8]].map(Vec::into_iter);
+        for expected in [[1, 2], [3, 4], [7, 8]] {
+            let mut tournament =
+                Tournament::from_iters_by_ref(&mut sources, MinComparator::default());
+            assert_eq!(tournament.by_ref().take(2).collect::<Vec<_>>(), expected);
+            // Put the buffered elements back for the next merge
+            for (item, source) in tournament.into_inner() {
+                if let Some(item) = item {
+                    *source = std::iter::once(item)
+                        .chain(source.by_ref())
+                        .collect::<Vec<_>>()
+                        .into_iter();
+                }
+            }
+        }
+        assert!(sources.iter().all(|source| source.len() == 0));
+    }
+
+    #[test]
+    fn test_tiebreak() {
+        // Sources with a lower priority value win draws
+        let priority = [2, 0, 1, 1, 0];
+        let mut tournament = Tournament::from_iters_with_tiebreak(
+            [
+                vec![(1, 'a'), (2, 'a')],
+                vec![(1, 'b'), (3, 'b')],
+                vec![],
+                vec![(1, 'd'), (2, 'd'), (3, 'd')],
+            ]
+            .map(Vec::into_iter),
+            by_key(|&(key, _): &(u32, char)| key),
+            move |a: usize, b: usize| priority[a].cmp(&priority[b]).then(a.cmp(&b)),
+        );
+        assert_eq!(tournament.next(), Some((1, 'b')));
+        assert_eq!(tournament.next_back(), Some((3, 'd')));
+
+        // The sources keep their ids when others are dropped
+        tournament.retain_sources(|&(_, c)| c != 'a');
+        tournament.push_iter(vec![(2, 'e')].into_iter());
+        let merged = tournament.map(|(_, c)| c).collect::<String>();
+        assert_eq!(merged, "dedb");
+    }
+
+    #[test]
+    fn test_extend() {
+        let mut tournament = Tournament::from_iters_min([vec![1, 5]].map(Vec::into_iter));
+        assert_eq!(tournament.next(), Some(1));
+
+        tournament.extend([vec![2, 3], vec![], vec![4, 6]].map(Vec::into_iter));
+        assert_eq!(tournament.active_sources(), 3);
+        assert_eq!(tournament.sources_len(), 4);
+        assert_eq!(tournament.collect::<Vec<_>>(), [2, 3, 4, 5, 6]);
+
+        // The heap and the sources grow once for the whole batch
+        let mut tournament = Tournament::from_iters_min([vec![0]].map(Vec::into_iter));
+        tournament.extend((1..=100).map(|i| vec![i].into_iter()));
+        assert!(tournament.heap_capacity() >= 101);
+        assert!(tournament.results.capacity() >= 101);
+        assert!(tournament.ids.capacity() >= 101);
+    }
+
+    #[test]
+    fn test_capacity_from_size_hint() {
+        let vecs = (0..1000).map(|i| vec![i, i + 1]).collect::<Vec<_>>();
+        let tournament = Tournament::from_iters_min(vecs.iter().map(|v| v.iter()));
+
+        assert_eq!(tournament.heap_capacity(), 1000);
+        assert_eq!(tournament.heap_len(), 1000);
+        assert_eq!(tournament.results.capacity(), 1000);
+
+        let tournament =
+            Tournament::<std::ops::Range<u32>, _>::with_capacity(10, MinComparator::default());
+        assert_eq!(tournament.heap_capacity(), 10);
+        assert_eq!(tournament.heap_len(), 0);
+    }
+
+    #[test]
+    fn test_empty_sources_dropped() {
+        let vecs = (0..1000)
+            .map(|_| vec![])
+            .chain([vec![1, 3], vec![2]])
+            .collect::<Vec<_>>();
+        let tournament = Tournament::from_iters_min(vecs.iter().map(|v| v.iter()));
+
+        assert_eq!(tournament.results.len(), 2);
+        #[cfg(not(feature = "smallvec"))]
+        assert_eq!(tournament.results.capacity(), 2);
+        // Shrunk back to the inline buffer
+        #[cfg(feature = "smallvec")]
+        assert!(!tournament.results.spilled());
+        assert_eq!(tournament.sources_len(), 1002);
+        assert_eq!(tournament.collect::<Vec<_>>(), [&1, &2, &3]);
+    }
+
+    #[test]
+    fn test_try_from_iters() {
+        let tournament =
+            Tournament::try_from_iters([vec![1, 4], vec![], vec![2, 3]], MinComparator::default())
+                .unwrap();
+        assert_eq!(tournament.collect::<Vec<_>>(), [1, 2, 3, 4]);
+
+        let err = Tournament::try_from_iters([vec![4, 1]], MinComparator::default()).unwrap_err();
+        assert_eq!(err, UnsortedError::new(0, 1));
+    }
+
+    #[test]
+    fn test_from_iters_dir() {
+        let sources = [vec![1, 4, 7], vec![2, 5], vec![], vec![3, 6]];
+
+        let ascending = Tournament::from_iters_dir(sources.clone().map(Vec::into_iter), true);
+        assert_eq!(ascending.collect::<Vec<_>>(), [1, 2, 3, 4, 5, 6, 7]);
+
+        let descending = Tournament::from_iters_dir(
+            sources.map(|v| v.into_iter().rev().collect::<Vec<_>>().into_iter()),
+            false,
+        );
+        assert_eq!(descending.collect::<Vec<_>>(), [7, 6, 5, 4, 3, 2, 1]);
+    }
+
+    #[test]
+    fn test_reverse_items() {
+        let sources = [vec![9, 4, 1], vec![8, 4], vec![], vec![7, 0]];
+
+        let reversed = Tournament::from_iters_min(
+            sources
+                .clone()
+                .map(|v| v.into_iter().map(Reverse).collect::<Vec<_>>().into_iter()),
+        )
+        .map(|Reverse(i)| i)
+        .collect::<Vec<_>>();
+        let descending =
+            Tournament::from_iters_max(sources.clone().map(Vec::into_iter)).collect::<Vec<_>>();
+
+        assert_eq!(reversed, [9, 8, 7, 4, 4, 1, 0]);
+        assert_eq!(reversed, descending);
+
+        // An ascending merge of the same sources, yielded from the back
+        let ascending = Tournament::from_iters_min(
+            sources.map(|v| v.into_iter().rev().collect::<Vec<_>>().into_iter()),
+        );
+        assert_eq!(ascending.rev().collect::<Vec<_>>(), descending);
+    }
+
+    #[test]
+    fn test_from_iters_ref() {
+        let keys: [&[u8]; 5] = [b"a", b"ab", b"b", b"ba", b"c"];
+        let t = Tournament::from_iters_ref(
+            [vec![keys[0], keys[3]], vec![keys[1], keys[2], keys[4]]].map(Vec::into_iter),
+            MinComparator::<[u8]>::default(),
+        );
+        assert_eq!(t.collect::<Vec<_>>(), keys);
+    }
+
+    #[test]
+    fn test_from_iters_with_transforms() {
+        // Timestamps in seconds, milliseconds and microseconds
+        let t = Tournament::from_iters_with_transforms(
+            [
+                (
+                    vec![1, 4, 7],
+                    Box::new(|s: u64| s * 1_000_000) as Box<dyn Fn(u64) -> u64>,
+                ),
+                (vec![2_000, 5_000], Box::new(|ms: u64| ms * 1_000)),
+                (vec![3_000_000, 6_000_000], Box::new(|us: u64| us)),
+            ],
+            MinComparator::default(),
+        );
+        assert_eq!(
+            t.map(|us| us / 1_000_000).collect::<Vec<_>>(),
+            [1, 2, 3, 4, 5, 6, 7]
+        );
+    }
+
+    #[test]
+    fn test_min_f64() {
+        let tournament = Tournament::from_iters_min_f64(
+            [
+                vec![-1.5, 0.0, 2.5, f64::NAN],
+                vec![-f64::INFINITY, 0.5, 1.0],
+            ]
+            .map(Vec::into_iter),
+        );
+        let result = tournament.collect::<Vec<_>>();
+
+        assert_eq!(result[..6], [-f64::INFINITY, -1.5, 0.0, 0.5, 1.0, 2.5]);
+        assert!(result[6].is_nan());
+
+        let tournament =
+            Tournament::from_iters_min_f32([[1.0f32, 3.0].iter().copied(), [2.0].iter().copied()]);
+        assert_eq!(tournament.collect::<Vec<_>>(), [1.0, 2.0, 3.0]);
+    }
+
+    #[test]
+    fn test_non_clone_comparator() {
+        struct Table(Vec<u8>);
+
+        impl Comparator<u8> for Table {
+            fn cmp(&self, a: &u8, b: &u8) -> Ordering {
+                self.0[*a as usize].cmp(&self.0[*b as usize])
+            }
+        }
+
+        let table = Table((0..=255).rev().collect());
+        let tournament = Tournament::from_iters([[3u8, 1].into_iter(), [2, 0].into_iter()], table);
+        assert_eq!(tournament.collect::<Vec<_>>(), [3, 2, 1, 0]);
+    }
+
+    #[test]
+    fn test_send_sync() {
+        fn assert_send<X: Send>() {}
+        fn assert_sync<X: Sync>() {}
+
+        type Owned = Tournament<std::vec::IntoIter<String>, MinComparator<String>>;
+        assert_send::<Owned>();
+        assert_sync::<Owned>();
+
+        type Shared<'a> = Tournament<
+            std::slice::Iter<'a, u32>,
+            std::sync::Arc<dyn Comparator<&'a u32> + Send + Sync>,
+            4,
+        >;
+        assert_send::<Shared<'_>>();
+        assert_sync::<Shared<'_>>();
+
+        let t = Tournament::from_sorted_vecs_min(vec![vec![1, 3], vec![2]]);
+        let merged = std::thread::spawn(move || t.collect::<Vec<_>>())
+            .join()
+            .unwrap();
+        assert_eq!(merged, [1, 2, 3]);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    fn test_panicking_comparator_poisons() {
+        struct PanicsOn(u32);
+
+        impl Comparator<u32> for PanicsOn {
+            fn cmp(&self, a: &u32, b: &u32) -> Ordering {
+                assert!(*a != self.0 && *b != self.0, "cannot compare {}", self.0);
+                a.cmp(b)
+            }
+        }
+
+        let mut t = Tournament::from_iters([0..3, 5..8, 10..12], PanicsOn(6));
+        assert_eq!(t.by_ref().take(3).collect::<Vec<_>>(), [0, 1, 2]);
+        // Pulling 6 from its source compares it with the head of the first one
+        assert!(std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| t.next())).is_err());
+
+        let err = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| t.next())).unwrap_err();
+        assert!(err
+            .downcast_ref::<&str>()
+            .unwrap()
+            .contains("the tournament is poisoned"));
+
+        t.clear();
+        t.push_iter(0..2);
+        assert_eq!(t.collect::<Vec<_>>(), [0, 1]);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "source 1 is not sorted")]
+    fn test_unsorted_source_panics() {
+        let tournament =
+            Tournament::from_iters_min([vec![1, 2, 3], vec![2, 1]].map(Vec::into_iter));
+        tournament.for_each(drop);
     }
 }
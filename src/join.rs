@@ -0,0 +1,174 @@
+use std::cmp::Ordering;
+
+/// Which unmatched elements a [`MergeJoinBy`] yields, in addition to the pairs of
+/// matching elements.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum JoinKind {
+    /// Only pairs of matching elements
+    Inner,
+    /// Every element of the left source, matched or not
+    Left,
+    /// Every element of the right source, matched or not
+    Right,
+    /// Every element of both sources, matched or not
+    Full,
+}
+
+/// An iterator that aligns the elements of two sorted sources with equal keys.
+///
+/// Created by [`merge_join_by`].
+#[derive(Clone, Debug)]
+pub struct MergeJoinBy<A, B, F>
+where
+    A: Iterator,
+    B: Iterator,
+{
+    a: A,
+    b: B,
+    head_a: Option<A::Item>,
+    head_b: Option<B::Item>,
+    cmp: F,
+    kind: JoinKind,
+}
+
+/// Join two sources that are sorted by the key compared by `cmp`, yielding a pair
+/// for every element, with `None` on the side that has no matching element. Equal
+/// elements are matched one to one, in order, so a run of `n` equal elements on
+/// one side and `m` on the other yields `max(n, m)` pairs in a full join. `kind`
+/// decides which unmatched elements are yielded.
+///
+/// # Examples
+///
+/// ```
+/// use tournament_kway::{merge_join_by, JoinKind};
+///
+/// let users = [(1, "ann"), (2, "bob"), (4, "eve")];
+/// let orders = [(1, 9.5), (3, 2.0), (4, 1.0)];
+///
+/// let joined = merge_join_by(users, orders, |u, o| u.0.cmp(&o.0), JoinKind::Left)
+///     .map(|(u, o)| (u.unwrap().1, o.map(|o| o.1)))
+///     .collect::<Vec<_>>();
+/// assert_eq!(joined, [("ann", Some(9.5)), ("bob", None), ("eve", Some(1.0))]);
+/// ```
+pub fn merge_join_by<A, B, F>(
+    a: A,
+    b: B,
+    cmp: F,
+    kind: JoinKind,
+) -> MergeJoinBy<A::IntoIter, B::IntoIter, F>
+where
+    A: IntoIterator,
+    B: IntoIterator,
+    F: FnMut(&A::Item, &B::Item) -> Ordering,
+{
+    let mut a = a.into_iter();
+    let mut b = b.into_iter();
+    MergeJoinBy {
+        head_a: a.next(),
+        head_b: b.next(),
+        a,
+        b,
+        cmp,
+        kind,
+    }
+}
+
+impl<A, B, F> Iterator for MergeJoinBy<A, B, F>
+where
+    A: Iterator,
+    B: Iterator,
+    F: FnMut(&A::Item, &B::Item) -> Ordering,
+{
+    type Item = (Option<A::Item>, Option<B::Item>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let order = match (&self.head_a, &self.head_b) {
+                (None, None) => return None,
+                (Some(_), None) => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (Some(a), Some(b)) => (self.cmp)(a, b),
+            };
+
+            let pair = match order {
+                Ordering::Less => (std::mem::replace(&mut self.head_a, self.a.next()), None),
+                Ordering::Equal => (
+                    std::mem::replace(&mut self.head_a, self.a.next()),
+                    std::mem::replace(&mut self.head_b, self.b.next()),
+                ),
+                Ordering::Greater => (None, std::mem::replace(&mut self.head_b, self.b.next())),
+            };
+
+            let keep = match self.kind {
+                JoinKind::Inner => pair.0.is_some() && pair.1.is_some(),
+                JoinKind::Left => pair.0.is_some(),
+                JoinKind::Right => pair.1.is_some(),
+                JoinKind::Full => true,
+            };
+            if keep {
+                return Some(pair);
+            }
+
+            // Nothing left on the side every yielded pair needs
+            let exhausted = match self.kind {
+                JoinKind::Inner => self.head_a.is_none() || self.head_b.is_none(),
+                JoinKind::Left => self.head_a.is_none(),
+                JoinKind::Right => self.head_b.is_none(),
+                JoinKind::Full => false,
+            };
+            if exhausted {
+                return None;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{merge_join_by, JoinKind};
+
+    #[test]
+    fn test_merge_join_by() {
+        let a = [1, 2, 2, 4, 6];
+        let b = [2, 3, 4, 4, 7];
+        let join = |kind| merge_join_by(a, b, |a, b| a.cmp(b), kind).collect::<Vec<_>>();
+
+        assert_eq!(
+            join(JoinKind::Full),
+            [
+                (Some(1), None),
+                (Some(2), Some(2)),
+                (Some(2), None),
+                (None, Some(3)),
+                (Some(4), Some(4)),
+                (None, Some(4)),
+                (Some(6), None),
+                (None, Some(7)),
+            ]
+        );
+        assert_eq!(
+            join(JoinKind::Inner),
+            [(Some(2), Some(2)), (Some(4), Some(4))]
+        );
+        assert_eq!(
+            join(JoinKind::Left),
+            [
+                (Some(1), None),
+                (Some(2), Some(2)),
+                (Some(2), None),
+                (Some(4), Some(4)),
+                (Some(6), None),
+            ]
+        );
+        assert_eq!(
+            join(JoinKind::Right),
+            [
+                (Some(2), Some(2)),
+                (None, Some(3)),
+                (Some(4), Some(4)),
+                (None, Some(4)),
+                (None, Some(7)),
+            ]
+        );
+    }
+}
@@ -13,10 +13,48 @@
 //! assert_eq!(t.take(5).collect::<Vec<_>>(), [1, 1, 1, 2, 2]);
 //!
 //! ```
+mod adapters;
+mod array_tournament;
+mod cached_key;
 mod comparator;
+mod concat;
+mod dary_heap;
+mod double_ended;
+mod error;
+#[cfg(feature = "std")]
+pub mod external_sort;
+mod galloping;
 mod iter_tournament;
+mod join;
+mod merge;
+mod merge2;
+#[cfg(feature = "rayon")]
+mod parallel;
+mod queue_tournament;
+mod set_ops;
+mod storage;
 mod streaming_tournament;
+mod tie_break;
 
+#[cfg(feature = "std")]
+pub use adapters::TakeUntil;
+pub use adapters::{
+    Checked, Chunks, Coalesce, DedupKeep, Filter, FilterMap, GroupByKey, InspectSource,
+    LazyTournament, Lending, LimitedTournament, MapMonotonic, MappedUnion, MergeStats,
+    PeekableTournament, Ranked, Runs, StepBy, TakeWhileKey, WithStats,
+};
+pub use array_tournament::ArrayTournament;
+pub use cached_key::CachedKeyTournament;
 pub use comparator::*;
-pub use iter_tournament::Tournament;
+pub use concat::Concatenated;
+pub use error::UnsortedError;
+pub use galloping::GallopingTournament;
+pub use iter_tournament::{Tournament, TournamentState};
+pub use join::{merge_join_by, JoinKind, MergeJoinBy};
+pub use merge::{merge_all, merge_all_desc, merge_by_cached_key};
+pub use merge2::Merge2;
+pub use queue_tournament::{
+    BucketQueue, ComparatorQueue, Contestant, PriorityQueue, QueueTournament,
+};
+pub use set_ops::{Difference, Intersection, Union};
 pub use streaming_tournament::StreamingTournament;
@@ -0,0 +1,78 @@
+use crate::{
+    cached_key::CachedKeyTournament,
+    comparator::{MaxComparator, MinComparator},
+    Tournament,
+};
+
+/// Merge sources that are sorted from smallest to largest into a single ascending
+/// iterator. This is a shorthand for [`Tournament::from_iters_min`].
+///
+/// # Examples
+///
+/// ```
+/// let merged = tournament_kway::merge_all([vec![1, 4, 7], vec![2, 5], vec![3, 6]]);
+/// assert_eq!(merged.collect::<Vec<_>>(), [1, 2, 3, 4, 5, 6, 7]);
+/// ```
+pub fn merge_all<I>(
+    iters: I,
+) -> Tournament<<I::Item as IntoIterator>::IntoIter, MinComparator<<I::Item as IntoIterator>::Item>>
+where
+    I: IntoIterator,
+    I::Item: IntoIterator,
+    <I::Item as IntoIterator>::Item: Ord,
+{
+    Tournament::from_iters_min(iters.into_iter().map(IntoIterator::into_iter))
+}
+
+/// Merge sources that are sorted from largest to smallest into a single descending
+/// iterator. This is a shorthand for [`Tournament::from_iters_max`].
+///
+/// # Examples
+///
+/// ```
+/// let merged = tournament_kway::merge_all_desc([vec![7, 4, 1], vec![5, 2], vec![6, 3]]);
+/// assert_eq!(merged.collect::<Vec<_>>(), [7, 6, 5, 4, 3, 2, 1]);
+/// ```
+pub fn merge_all_desc<I>(
+    iters: I,
+) -> Tournament<<I::Item as IntoIterator>::IntoIter, MaxComparator<<I::Item as IntoIterator>::Item>>
+where
+    I: IntoIterator,
+    I::Item: IntoIterator,
+    <I::Item as IntoIterator>::Item: Ord,
+{
+    Tournament::from_iters_max(iters.into_iter().map(IntoIterator::into_iter))
+}
+
+/// Merge sources that are sorted from smallest to largest key, as extracted by
+/// `key_fn`, into a single ascending iterator. The key of every element is
+/// computed once, when it is pulled from its source, and kept for all of its
+/// comparisons, so this is the one to use when the key is expensive to compute.
+/// This is a shorthand for [`CachedKeyTournament::from_iters`].
+///
+/// Merging `n` elements from `k` sources calls `key_fn` `n` times and makes
+/// `O(n log k)` comparisons of keys, at the cost of storing a key for every source.
+/// The sources must be sorted by the same key, or the order of the output is
+/// unspecified.
+///
+/// # Examples
+///
+/// ```
+/// let merged = tournament_kway::merge_by_cached_key(
+///     [vec!["b=1", "a=3"], vec!["c=2"]],
+///     |s: &&str| s[2..].parse::<u32>().unwrap(),
+/// );
+/// assert_eq!(merged.collect::<Vec<_>>(), ["b=1", "c=2", "a=3"]);
+/// ```
+pub fn merge_by_cached_key<I, K, F>(
+    iters: I,
+    key_fn: F,
+) -> CachedKeyTournament<<I::Item as IntoIterator>::IntoIter, K, F>
+where
+    I: IntoIterator,
+    I::Item: IntoIterator,
+    K: Ord,
+    F: FnMut(&<I::Item as IntoIterator>::Item) -> K,
+{
+    CachedKeyTournament::from_iters(iters.into_iter().map(IntoIterator::into_iter), key_fn)
+}
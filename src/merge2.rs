@@ -0,0 +1,107 @@
+use crate::comparator::Comparator;
+
+/// An iterator that merges exactly two sources by comparing their current
+/// elements directly, without the overhead of a heap.
+///
+/// Created by [`Tournament::merge2`](crate::Tournament::merge2).
+#[derive(Clone, Debug)]
+pub struct Merge2<T, C>
+where
+    T: Iterator,
+{
+    a: T,
+    b: T,
+    head_a: Option<T::Item>,
+    head_b: Option<T::Item>,
+    // Which source wins a draw, matching the choice the heap would make
+    a_wins_draw: bool,
+    comparator: C,
+}
+
+impl<T, C> Merge2<T, C>
+where
+    T: Iterator,
+{
+    pub(crate) fn new(mut a: T, mut b: T, comparator: C) -> Self {
+        Merge2 {
+            head_a: a.next(),
+            head_b: b.next(),
+            a,
+            b,
+            a_wins_draw: true,
+            comparator,
+        }
+    }
+}
+
+impl<T, C> Iterator for Merge2<T, C>
+where
+    T: Iterator,
+    C: Comparator<T::Item>,
+{
+    type Item = T::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let take_a = match (&self.head_a, &self.head_b) {
+            (None, None) => return None,
+            (Some(_), None) => true,
+            (None, Some(_)) => false,
+            (Some(a), Some(b)) => match self.comparator.cmp(a, b) {
+                std::cmp::Ordering::Less => true,
+                std::cmp::Ordering::Equal => self.a_wins_draw,
+                std::cmp::Ordering::Greater => false,
+            },
+        };
+
+        // The heap keeps the source that did not just advance on top on a draw
+        self.a_wins_draw = !take_a;
+        if take_a {
+            std::mem::replace(&mut self.head_a, self.a.next())
+        } else {
+            std::mem::replace(&mut self.head_b, self.b.next())
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let heads = self.head_a.is_some() as usize + self.head_b.is_some() as usize;
+        let (lower_a, upper_a) = self.a.size_hint();
+        let (lower_b, upper_b) = self.b.size_hint();
+        (
+            heads.saturating_add(lower_a).saturating_add(lower_b),
+            upper_a
+                .zip(upper_b)
+                .and_then(|(a, b)| a.checked_add(b))
+                .and_then(|u| u.checked_add(heads)),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::Rng;
+
+    use crate::{by_key, Tournament};
+
+    #[test]
+    fn test_merge2_matches_heap() {
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..100 {
+            let sources = [0, 1].map(|tag| {
+                let mut v = (0..rng.gen_range(0..100))
+                    .map(|_| (rng.gen_range(0..20), tag))
+                    .collect::<Vec<(u32, u32)>>();
+                v.sort();
+                v
+            });
+
+            let comparator = by_key(|&(key, _): &(u32, u32)| key);
+            let heap = Tournament::from_iters(sources.clone().map(Vec::into_iter), comparator)
+                .collect::<Vec<_>>();
+            let [a, b] = sources.map(Vec::into_iter);
+            let two_way = Tournament::merge2(a, b, comparator).collect::<Vec<_>>();
+
+            assert_eq!(two_way, heap);
+        }
+    }
+}
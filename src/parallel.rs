@@ -0,0 +1,91 @@
+use rayon::prelude::*;
+
+use crate::{comparator::Comparator, Tournament};
+
+impl<T, C> Tournament<T, C>
+where
+    T: Iterator + Send,
+    T::Item: Send,
+    C: Comparator<T::Item> + Sync,
+{
+    /// Collect the first `n` merged elements of `iters`, as
+    /// [`Tournament::top_n`] does, splitting the sources between the threads of
+    /// the current rayon pool. Every thread merges its share of the sources into a
+    /// local top `n`, and the local results are merged into the global one.
+    ///
+    /// The sources are split into one share per thread, so the number of threads
+    /// is that of the pool the call runs in, which can be tuned by running it in a
+    /// [`rayon::ThreadPool`] with [`rayon::ThreadPool::install`]. This pays off
+    /// when there are many sources and pulling their first elements is expensive;
+    /// for a few sources the single threaded merge is faster.
+    ///
+    /// Elements that draw may be yielded in a different order than with
+    /// [`Tournament::top_n`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tournament_kway::{MinComparator, Tournament};
+    ///
+    /// let sources = (0..100).map(|i| (i..i + 1000)).collect::<Vec<_>>();
+    /// let top = Tournament::par_top_n(sources, 4, MinComparator::default());
+    /// assert_eq!(top, [0, 1, 1, 2]);
+    /// ```
+    pub fn par_top_n<I: IntoIterator<Item = T>>(iters: I, n: usize, comparator: C) -> Vec<T::Item> {
+        let mut sources = iters.into_iter().collect::<Vec<_>>();
+        let share_len = sources.len().div_ceil(rayon::current_num_threads()).max(1);
+
+        let partial = sources
+            .par_chunks_mut(share_len)
+            .map(|share| Tournament::from_iters(share.iter_mut(), &comparator).top_n(n))
+            .collect::<Vec<_>>();
+
+        Tournament::from_iters(partial.into_iter().map(Vec::into_iter), &comparator).top_n(n)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::Rng;
+
+    use crate::{MinComparator, Tournament};
+
+    #[test]
+    fn test_par_top_n() {
+        let mut rng = rand::thread_rng();
+
+        let vecs = (0..1000)
+            .map(|_| {
+                let mut v = (0..rng.gen_range(0..50))
+                    .map(|_| rng.gen_range(0..100000))
+                    .collect::<Vec<u32>>();
+                v.sort();
+                v
+            })
+            .collect::<Vec<_>>();
+
+        for n in [0, 1, 10, 1000, 100000] {
+            let expected = Tournament::from_iters_min(vecs.iter().map(|v| v.iter())).top_n(n);
+            let pool = rayon::ThreadPoolBuilder::new()
+                .num_threads(4)
+                .build()
+                .unwrap();
+            let parallel = pool.install(|| {
+                Tournament::par_top_n(vecs.iter().map(|v| v.iter()), n, MinComparator::default())
+            });
+            assert_eq!(parallel, expected);
+        }
+
+        // The sources are counted before splitting them, even when the iterator
+        // over them does not know its length
+        let filtered = || vecs.iter().filter(|v| v.len() % 2 == 0).map(|v| v.iter());
+        let expected = Tournament::from_iters_min(filtered()).top_n(100);
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(4)
+            .build()
+            .unwrap();
+        let parallel =
+            pool.install(|| Tournament::par_top_n(filtered(), 100, MinComparator::default()));
+        assert_eq!(parallel, expected);
+    }
+}
@@ -0,0 +1,384 @@
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+use crate::comparator::Comparator;
+use crate::dary_heap::DaryHeap;
+
+/// A queue of contestants that yields them in the order they win, which decides
+/// the order of a [`QueueTournament`].
+///
+/// Implementing it allows a domain specific queue to replace the heap, such as a
+/// [`BucketQueue`] for small integer keys.
+pub trait PriorityQueue<E> {
+    /// Add a contestant to the queue.
+    fn push(&mut self, entry: E);
+
+    /// Remove the winning contestant from the queue.
+    fn pop(&mut self) -> Option<E>;
+
+    /// The contestant the next call to `pop` will return.
+    fn peek(&self) -> Option<&E>;
+
+    /// The number of contestants in the queue.
+    fn len(&self) -> usize;
+
+    /// Returns `true` if the queue holds no contestants.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// A [`BinaryHeap`] yields the largest contestant first, which is the one with the
+/// smallest item, as [`Contestant`] orders them in reverse.
+impl<E: Ord> PriorityQueue<E> for BinaryHeap<E> {
+    #[inline(always)]
+    fn push(&mut self, entry: E) {
+        BinaryHeap::push(self, entry)
+    }
+
+    #[inline(always)]
+    fn pop(&mut self) -> Option<E> {
+        BinaryHeap::pop(self)
+    }
+
+    #[inline(always)]
+    fn peek(&self) -> Option<&E> {
+        BinaryHeap::peek(self)
+    }
+
+    #[inline(always)]
+    fn len(&self) -> usize {
+        BinaryHeap::len(self)
+    }
+}
+
+/// The current element of a source in a [`QueueTournament`], together with the
+/// index of the source.
+///
+/// Contestants are ordered in reverse of their items, and then of their sources,
+/// so a max-heap such as [`BinaryHeap`] yields the smallest item first, and the
+/// first source of those that draw.
+#[derive(Clone, Copy, Debug)]
+pub struct Contestant<I> {
+    /// The current element of the source
+    pub item: I,
+    /// The index of the source among the sources of the tournament
+    pub source: usize,
+}
+
+impl<I: Ord> Ord for Contestant<I> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .item
+            .cmp(&self.item)
+            .then(other.source.cmp(&self.source))
+    }
+}
+
+impl<I: Ord> PartialOrd for Contestant<I> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<I: Ord> PartialEq for Contestant<I> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other).is_eq()
+    }
+}
+
+impl<I: Ord> Eq for Contestant<I> {}
+
+/// A [`PriorityQueue`] that orders contestants with a [`Comparator`] of their
+/// items, and then by their sources, so the first source of those that draw wins.
+/// It is the queue of [`QueueTournament::from_iters_by`], for orders other than
+/// the ascending one of a [`BinaryHeap`].
+///
+/// # Examples
+///
+/// ```
+/// use tournament_kway::{ComparatorQueue, MaxComparator, QueueTournament};
+///
+/// let t = QueueTournament::from_iters(
+///     [(1..4).rev(), (2..5).rev()],
+///     ComparatorQueue::new(MaxComparator::default()),
+/// );
+/// assert_eq!(t.collect::<Vec<_>>(), [4, 3, 3, 2, 2, 1]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct ComparatorQueue<I, C> {
+    heap: DaryHeap<Contestant<I>, 2>,
+    comparator: C,
+}
+
+impl<I, C> ComparatorQueue<I, C> {
+    /// Create an empty queue that orders contestants by `comparator`.
+    pub fn new(comparator: C) -> Self {
+        ComparatorQueue {
+            heap: DaryHeap::with_capacity(0),
+            comparator,
+        }
+    }
+}
+
+/// The order of contestants in a [`ComparatorQueue`], winner first.
+fn contestant_order<I, C: Comparator<I>>(
+    comparator: &C,
+) -> impl Fn(&Contestant<I>, &Contestant<I>) -> Ordering + '_ {
+    move |a, b| {
+        comparator
+            .cmp(&a.item, &b.item)
+            .then(a.source.cmp(&b.source))
+    }
+}
+
+impl<I, C: Comparator<I>> PriorityQueue<Contestant<I>> for ComparatorQueue<I, C> {
+    fn push(&mut self, entry: Contestant<I>) {
+        self.heap.push(entry, contestant_order(&self.comparator));
+    }
+
+    fn pop(&mut self) -> Option<Contestant<I>> {
+        self.heap.pop(contestant_order(&self.comparator))
+    }
+
+    fn peek(&self) -> Option<&Contestant<I>> {
+        self.heap.peek()
+    }
+
+    fn len(&self) -> usize {
+        self.heap.len()
+    }
+}
+
+/// A [`PriorityQueue`] for contestants with small integer items, which keeps a
+/// bucket per possible item instead of a heap. Pushing and popping are O(1),
+/// except for scanning past empty buckets, which the sorted sources of a merge
+/// only ever do forwards.
+///
+/// Contestants with equal items are yielded in no particular order.
+///
+/// # Examples
+///
+/// ```
+/// use tournament_kway::{BucketQueue, QueueTournament};
+///
+/// let t = QueueTournament::from_iters(
+///     [vec![1u8, 3], vec![2, 200]].map(Vec::into_iter),
+///     BucketQueue::default(),
+/// );
+/// assert_eq!(t.collect::<Vec<_>>(), [1, 2, 3, 200]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct BucketQueue<K> {
+    buckets: Vec<Vec<Contestant<K>>>,
+    // The first bucket that holds contestants, if any do
+    first: usize,
+    len: usize,
+}
+
+impl<K> Default for BucketQueue<K> {
+    fn default() -> Self {
+        BucketQueue {
+            buckets: Vec::new(),
+            first: 0,
+            len: 0,
+        }
+    }
+}
+
+impl<K> PriorityQueue<Contestant<K>> for BucketQueue<K>
+where
+    K: Copy + Into<usize>,
+{
+    fn push(&mut self, entry: Contestant<K>) {
+        let bucket = entry.item.into();
+        if bucket >= self.buckets.len() {
+            self.buckets.resize_with(bucket + 1, Vec::new);
+        }
+        self.buckets[bucket].push(entry);
+        if self.len == 0 || bucket < self.first {
+            self.first = bucket;
+        }
+        self.len += 1;
+    }
+
+    fn pop(&mut self) -> Option<Contestant<K>> {
+        let entry = self.buckets.get_mut(self.first)?.pop()?;
+        self.len -= 1;
+        // Move on to the next contestant, unless the queue is now empty
+        while self.len > 0 && self.buckets[self.first].is_empty() {
+            self.first += 1;
+        }
+        Some(entry)
+    }
+
+    fn peek(&self) -> Option<&Contestant<K>> {
+        self.buckets.get(self.first)?.last()
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+}
+
+/// A tournament whose contestants are kept in a [`PriorityQueue`] of choice, which
+/// also decides their order, instead of the built-in heap of a
+/// [`Tournament`](crate::Tournament). It is meant for queues that outperform a heap
+/// in a specific domain, such as a [`BucketQueue`] for small integer items, and
+/// has none of the other features of a `Tournament`.
+///
+/// `Tournament` itself is not generic over the queue, because most of what it
+/// offers works on the heap directly: consumption from the back splits the heap in
+/// two, [`Tournament::coalesce`](crate::Tournament::coalesce) and
+/// [`Tournament::peek_two`](crate::Tournament::peek_two) look at the children of
+/// its top, and removing sources or merging two tournaments takes all the entries
+/// out of it to renumber their sources. A [`PriorityQueue`] only offers push, pop
+/// and peek, so those features could not be built on top of one.
+///
+/// With a [`BinaryHeap`] the output is sorted from smallest to largest. Other
+/// orders are given by a [`Comparator`], with [`QueueTournament::from_iters_by`].
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::BinaryHeap;
+/// use tournament_kway::QueueTournament;
+///
+/// let t = QueueTournament::from_iters([(1..4), (2..5)], BinaryHeap::new());
+/// assert_eq!(t.collect::<Vec<_>>(), [1, 2, 2, 3, 3, 4]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct QueueTournament<T, Q>
+where
+    T: Iterator,
+{
+    queue: Q,
+    results: Vec<T>,
+}
+
+impl<T, Q> QueueTournament<T, Q>
+where
+    T: Iterator,
+    Q: PriorityQueue<Contestant<T::Item>>,
+{
+    /// Create a tournament over iterators that are sorted in the order `queue`
+    /// yields its contestants. The queue should be empty.
+    pub fn from_iters<I: IntoIterator<Item = T>>(iters: I, mut queue: Q) -> Self {
+        let results = iters
+            .into_iter()
+            .enumerate()
+            .map(|(source, mut iter)| {
+                if let Some(item) = iter.next() {
+                    queue.push(Contestant { item, source });
+                }
+                iter
+            })
+            .collect();
+
+        QueueTournament { queue, results }
+    }
+
+    /// The number of sources that still have elements left to merge.
+    pub fn active_sources(&self) -> usize {
+        self.queue.len()
+    }
+}
+
+impl<T, C> QueueTournament<T, ComparatorQueue<T::Item, C>>
+where
+    T: Iterator,
+    C: Comparator<T::Item>,
+{
+    /// Create a tournament over iterators that are sorted according to
+    /// `comparator`, kept in a [`ComparatorQueue`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tournament_kway::{by_key, QueueTournament};
+    ///
+    /// let t = QueueTournament::from_iters_by(
+    ///     [vec![(1, 'a'), (3, 'c')], vec![(2, 'b')]].map(Vec::into_iter),
+    ///     by_key(|&(key, _): &(u32, char)| key),
+    /// );
+    /// assert_eq!(t.map(|(_, c)| c).collect::<String>(), "abc");
+    /// ```
+    pub fn from_iters_by<I: IntoIterator<Item = T>>(iters: I, comparator: C) -> Self {
+        QueueTournament::from_iters(iters, ComparatorQueue::new(comparator))
+    }
+}
+
+impl<T, Q> Iterator for QueueTournament<T, Q>
+where
+    T: Iterator,
+    Q: PriorityQueue<Contestant<T::Item>>,
+{
+    type Item = T::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let Contestant { item, source } = self.queue.pop()?;
+        if let Some(next) = self.results[source].next() {
+            self.queue.push(Contestant { item: next, source });
+        }
+        Some(item)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BinaryHeap;
+
+    use rand::Rng;
+
+    use crate::{BucketQueue, MaxComparator, MinComparator, QueueTournament, Tournament};
+
+    #[test]
+    fn test_queues_match_heap() {
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..100 {
+            let sources = (0..rng.gen_range(0..10))
+                .map(|_| {
+                    let mut v = (0..rng.gen_range(0..50))
+                        .map(|_| rng.gen())
+                        .collect::<Vec<u8>>();
+                    v.sort();
+                    v
+                })
+                .collect::<Vec<_>>();
+
+            let expected = Tournament::from_iters_min(sources.iter().map(|v| v.iter().copied()))
+                .collect::<Vec<_>>();
+            let binary_heap = QueueTournament::from_iters(
+                sources.iter().map(|v| v.iter().copied()),
+                BinaryHeap::new(),
+            );
+            assert_eq!(binary_heap.collect::<Vec<_>>(), expected);
+
+            let mut bucket_queue = QueueTournament::from_iters(
+                sources.iter().map(|v| v.iter().copied()),
+                BucketQueue::default(),
+            );
+            assert_eq!(
+                bucket_queue.active_sources(),
+                sources.iter().filter(|v| !v.is_empty()).count()
+            );
+            assert_eq!(bucket_queue.by_ref().collect::<Vec<_>>(), expected);
+            assert_eq!(bucket_queue.active_sources(), 0);
+
+            let comparator_queue = QueueTournament::from_iters_by(
+                sources.iter().map(|v| v.iter().copied()),
+                MinComparator::default(),
+            );
+            assert_eq!(comparator_queue.collect::<Vec<_>>(), expected);
+
+            let expected = Tournament::from_iters_max(sources.iter().map(|v| v.iter().rev()))
+                .collect::<Vec<_>>();
+            let descending = QueueTournament::from_iters_by(
+                sources.iter().map(|v| v.iter().rev()),
+                MaxComparator::default(),
+            );
+            assert_eq!(descending.collect::<Vec<_>>(), expected);
+        }
+    }
+}
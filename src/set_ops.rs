@@ -0,0 +1,253 @@
+use std::cmp::Ordering;
+
+use crate::{comparator::Comparator, Tournament};
+
+/// An iterator over the elements present in every source of a [`Tournament`].
+///
+/// Created by [`Tournament::intersection`].
+pub struct Intersection<T, C, const D: usize = 2>
+where
+    T: Iterator,
+{
+    tournament: Tournament<T, C, D>,
+    // The run in which each source slot was last seen
+    seen: Vec<usize>,
+    // The number of sources an element must appear in, including the empty ones,
+    // which leave the intersection empty
+    sources: usize,
+    // The current run
+    run: usize,
+}
+
+impl<T, C, const D: usize> Intersection<T, C, D>
+where
+    T: Iterator,
+{
+    pub(crate) fn new(tournament: Tournament<T, C, D>) -> Self {
+        Intersection {
+            seen: vec![0; tournament.slots_len()],
+            sources: tournament.slots_len() + tournament.empty_sources(),
+            run: 0,
+            tournament,
+        }
+    }
+}
+
+impl<T, C, const D: usize> Iterator for Intersection<T, C, D>
+where
+    T: Iterator,
+    C: Comparator<T::Item>,
+{
+    type Item = T::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // Once any source is exhausted no further element can be common to all of them
+        while self.tournament.active_sources() == self.sources {
+            let (index, item) = self.tournament.next_with_index()?;
+
+            self.run += 1;
+            self.seen[index] = self.run;
+            let mut count = 1;
+
+            while let Some(head) = self.tournament.peek() {
+                if self.tournament.comparator().cmp(head, &item).is_ne() {
+                    break;
+                }
+                let (index, _) = self.tournament.next_with_index()?;
+                if self.seen[index] != self.run {
+                    self.seen[index] = self.run;
+                    count += 1;
+                }
+            }
+
+            if count == self.sources {
+                return Some(item);
+            }
+        }
+
+        None
+    }
+}
+
+/// An iterator over the distinct elements of all the sources of a [`Tournament`].
+///
+/// Created by [`Tournament::union`].
+pub struct Union<T, C, const D: usize = 2>
+where
+    T: Iterator,
+{
+    tournament: Tournament<T, C, D>,
+}
+
+impl<T, C, const D: usize> Union<T, C, D>
+where
+    T: Iterator,
+{
+    pub(crate) fn new(tournament: Tournament<T, C, D>) -> Self {
+        Union { tournament }
+    }
+}
+
+impl<T, C, const D: usize> Iterator for Union<T, C, D>
+where
+    T: Iterator,
+    C: Comparator<T::Item>,
+{
+    type Item = T::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.tournament.next()?;
+
+        while let Some(head) = self.tournament.peek() {
+            if self.tournament.comparator().cmp(head, &item).is_ne() {
+                break;
+            }
+            self.tournament.next();
+        }
+
+        Some(item)
+    }
+}
+
+/// An iterator over the elements of a primary iterator that are absent from all
+/// the sources of a [`Tournament`].
+///
+/// Created by [`Tournament::difference`].
+pub struct Difference<P, T, C>
+where
+    T: Iterator,
+{
+    primary: P,
+    others: Tournament<T, C>,
+}
+
+impl<P, T, C> Difference<P, T, C>
+where
+    T: Iterator,
+{
+    pub(crate) fn new(primary: P, others: Tournament<T, C>) -> Self {
+        Difference { primary, others }
+    }
+}
+
+impl<P, T, C> Iterator for Difference<P, T, C>
+where
+    P: Iterator<Item = T::Item>,
+    T: Iterator,
+    C: Comparator<T::Item>,
+{
+    type Item = T::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        'primary: for item in self.primary.by_ref() {
+            while let Some(head) = self.others.peek() {
+                match self.others.comparator().cmp(head, &item) {
+                    Ordering::Less => {
+                        self.others.next();
+                    }
+                    Ordering::Equal => continue 'primary,
+                    Ordering::Greater => break,
+                }
+            }
+            return Some(item);
+        }
+
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.primary.size_hint().1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use rand::Rng;
+
+    use crate::{MinComparator, Tournament};
+
+    #[test]
+    fn test_intersection() {
+        let mut rng = rand::thread_rng();
+
+        let sets = (0..5)
+            .map(|_| {
+                (0..500)
+                    .map(|_| rng.gen_range(0..1000))
+                    .collect::<BTreeSet<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        let tournament_result = Tournament::from_iters_min(sets.iter().map(|s| s.iter()))
+            .intersection()
+            .collect::<Vec<_>>();
+
+        let expected = (0..1000)
+            .filter(|i| sets.iter().all(|s| s.contains(i)))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            tournament_result.into_iter().copied().collect::<Vec<_>>(),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_intersection_empty_source() {
+        let t =
+            Tournament::from_iters_min([vec![1, 2, 3], vec![], vec![1, 2, 3]].map(Vec::into_iter));
+        assert_eq!(t.intersection().count(), 0);
+    }
+
+    #[test]
+    fn test_intersection_after_retain_sources() {
+        let mut t =
+            Tournament::from_iters_min([vec![1, 2], vec![5, 9], vec![1, 2]].map(Vec::into_iter));
+        t.retain_sources(|&head| head < 5);
+        assert_eq!(t.intersection().collect::<Vec<_>>(), [1, 2]);
+
+        let mut t =
+            Tournament::from_iters_min([vec![1, 2], vec![], vec![5, 9]].map(Vec::into_iter));
+        t.retain_sources(|&head| head < 5);
+        assert_eq!(t.intersection().count(), 0);
+    }
+
+    #[test]
+    fn test_intersection_after_merge() {
+        let a = Tournament::from_iters_min([vec![1, 2, 4], vec![2, 4]].map(Vec::into_iter));
+        let b = Tournament::from_iters_min([vec![2, 3, 4]].map(Vec::into_iter));
+        assert_eq!(a.merge(b).intersection().collect::<Vec<_>>(), [2, 4]);
+
+        let a = Tournament::from_iters_min([vec![1, 2, 4], vec![2, 4]].map(Vec::into_iter));
+        let b = Tournament::from_iters_min([vec![2, 4], vec![]].map(Vec::into_iter));
+        assert_eq!(a.merge(b).intersection().count(), 0);
+    }
+
+    #[test]
+    fn test_union() {
+        let t = Tournament::from_iters_min([(0..10), (5..15), (8..20)]);
+        assert_eq!(t.union().collect::<Vec<_>>(), (0..20).collect::<Vec<_>>());
+
+        let t = Tournament::from_iters_max([(0..10).rev(), (5..15).rev(), (8..20).rev()]);
+        assert_eq!(
+            t.union().collect::<Vec<_>>(),
+            (0..20).rev().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_difference() {
+        let d = Tournament::difference(
+            vec![1, 1, 2, 3, 5, 8, 13, 21],
+            [vec![1, 4, 8], vec![], vec![3, 3, 20, 21, 30]].map(Vec::into_iter),
+            MinComparator::default(),
+        );
+        assert_eq!(d.collect::<Vec<_>>(), [2, 5, 13]);
+
+        let no_others: [std::ops::Range<i32>; 0] = [];
+        let d = Tournament::difference(0..5, no_others, MinComparator::default());
+        assert_eq!(d.collect::<Vec<_>>(), [0, 1, 2, 3, 4]);
+    }
+}
@@ -0,0 +1,25 @@
+//! The buffers of the heap and the sources of a tournament. With the `smallvec`
+//! feature they are stored inline up to a small number of sources, so merges with
+//! few sources do not allocate.
+
+/// The number of elements a buffer stores inline with the `smallvec` feature.
+#[cfg(feature = "smallvec")]
+pub(crate) const INLINE: usize = 8;
+
+#[cfg(not(feature = "smallvec"))]
+pub(crate) type Storage<E> = Vec<E>;
+
+#[cfg(feature = "smallvec")]
+pub(crate) type Storage<E> = smallvec::SmallVec<[E; INLINE]>;
+
+/// Move the elements of a buffer to a [`Vec`].
+#[cfg(not(feature = "smallvec"))]
+pub(crate) fn into_vec<E>(storage: Storage<E>) -> Vec<E> {
+    storage
+}
+
+/// Move the elements of a buffer to a [`Vec`].
+#[cfg(feature = "smallvec")]
+pub(crate) fn into_vec<E>(storage: Storage<E>) -> Vec<E> {
+    storage.into_vec()
+}
@@ -1,8 +1,9 @@
-use std::{cmp::Ordering, collections::BinaryHeap};
+use std::cmp::Ordering;
 
-use streaming_iterator::StreamingIterator;
+use streaming_iterator::{StreamingIterator, StreamingIteratorMut};
 
 use crate::comparator::{Comparator, MaxComparator, MinComparator};
+use crate::dary_heap::DaryHeap;
 
 /// A tournament that implements [`StreamingIterator`] and merges [`StreamingIterator`]s
 #[derive(Clone, Debug)]
@@ -13,7 +14,9 @@ where
     // Indicates if first call to advance was made
     started: bool,
     // The tree that stores the "contestants"
-    tree: BinaryHeap<StreamingTournamentEntry<T, C>>,
+    tree: DaryHeap<T, 2>,
+    // The comparator shared by all the contestants
+    comparator: C,
 }
 
 impl<T> StreamingTournament<T, MinComparator<T::Item>>
@@ -29,6 +32,14 @@ where
     ) -> StreamingTournament<T, MinComparator<T::Item>> {
         StreamingTournament::from_iters(iters, MinComparator::default())
     }
+
+    /// A tournament that merges sources sorted in ascending order, from smallest to largest.
+    /// The same as [`StreamingTournament::from_iters_min`].
+    pub fn from_iters_ascending<I: IntoIterator<Item = T>>(
+        iters: I,
+    ) -> StreamingTournament<T, MinComparator<T::Item>> {
+        StreamingTournament::from_iters_min(iters)
+    }
 }
 
 impl<T> StreamingTournament<T, MaxComparator<T::Item>>
@@ -44,34 +55,125 @@ where
     ) -> StreamingTournament<T, MaxComparator<T::Item>> {
         StreamingTournament::from_iters(iters, MaxComparator::default())
     }
+
+    /// A tournament that merges sources sorted in descending order, from largest to smallest.
+    /// The same as [`StreamingTournament::from_iters_max`].
+    pub fn from_iters_descending<I: IntoIterator<Item = T>>(
+        iters: I,
+    ) -> StreamingTournament<T, MaxComparator<T::Item>> {
+        StreamingTournament::from_iters_max(iters)
+    }
 }
 
 impl<T, C> StreamingTournament<T, C>
 where
     T: StreamingIterator,
-    C: Comparator<T::Item> + Clone,
+    C: Comparator<T::Item>,
 {
     /// Create a tournament with a custom comparator
+    ///
+    /// Unlike [`Tournament`](crate::Tournament), unsorted sources are not detected
+    /// in debug builds, since the previous element of a source is gone once it advances.
     pub fn from_iters<I: IntoIterator<Item = T>>(
         iters: I,
         comparator: C,
     ) -> StreamingTournament<T, C> {
-        let mut tree = BinaryHeap::new();
+        let iters = iters.into_iter();
+        let mut tree = DaryHeap::with_capacity(iters.size_hint().0);
         for mut iter in iters {
             iter.advance();
             if iter.get().is_some() {
-                tree.push(StreamingTournamentEntry {
-                    iter,
-                    comparator: comparator.clone(),
-                });
+                tree.push(iter, iter_order(&comparator));
             }
         }
 
         StreamingTournament {
             tree,
+            comparator,
             started: false,
         }
     }
+
+    /// Advance the tournament `n` times. Returns `Err(k)` if the tournament was
+    /// exhausted after only `k` successful advances.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use streaming_iterator::StreamingIterator;
+    /// use tournament_kway::StreamingTournament;
+    ///
+    /// let mut t = StreamingTournament::from_iters_min([(1..3), (2..4)].map(streaming_iterator::convert));
+    /// assert_eq!(t.advance_by(2), Ok(()));
+    /// assert_eq!(t.get(), Some(&2));
+    /// assert_eq!(t.advance_by(5), Err(2));
+    /// ```
+    pub fn advance_by(&mut self, n: usize) -> Result<(), usize> {
+        for i in 0..n {
+            self.advance();
+            if self.get().is_none() {
+                return Err(i);
+            }
+        }
+        Ok(())
+    }
+
+    /// Advance the tournament up to `n` times, stopping early if it is exhausted.
+    /// Returns the number of successful advances, which is less than `n` if the
+    /// tournament ran out, for example to compute the offset of the next page.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use streaming_iterator::StreamingIterator;
+    /// use tournament_kway::StreamingTournament;
+    ///
+    /// let mut t = StreamingTournament::from_iters_min([(1..3), (2..4)].map(streaming_iterator::convert));
+    /// assert_eq!(t.advance_n(3), 3);
+    /// assert_eq!(t.get(), Some(&2));
+    /// assert_eq!(t.advance_n(5), 1);
+    /// assert_eq!(t.get(), None);
+    /// ```
+    pub fn advance_n(&mut self, n: usize) -> usize {
+        match self.advance_by(n) {
+            Ok(()) => n,
+            Err(k) => k,
+        }
+    }
+
+    /// The number of entries in the heap, one for every source that still has
+    /// elements left to merge, including the current one.
+    pub fn heap_len(&self) -> usize {
+        self.tree.len()
+    }
+
+    /// The number of entries the heap has room for without reallocating.
+    pub fn heap_capacity(&self) -> usize {
+        self.tree.capacity()
+    }
+
+    /// Consume the tournament as a regular [`Iterator`], yielding an owned clone
+    /// of every merged element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tournament_kway::StreamingTournament;
+    ///
+    /// let t = StreamingTournament::from_iters_min(
+    ///     [(1..2000), (1..20000), (1..5000000)].map(streaming_iterator::convert),
+    /// );
+    /// assert_eq!(t.cloned_iter().take(5).collect::<Vec<_>>(), [1, 1, 1, 2, 2]);
+    /// ```
+    pub fn cloned_iter(mut self) -> impl Iterator<Item = T::Item>
+    where
+        T::Item: Clone,
+    {
+        std::iter::from_fn(move || {
+            self.advance();
+            self.get().cloned()
+        })
+    }
 }
 
 impl<T, F> StreamingIterator for StreamingTournament<T, F>
@@ -87,97 +189,76 @@ where
             return;
         }
 
-        match self.tree.pop() {
-            None => {}
-            Some(StreamingTournamentEntry {
-                mut iter,
-                comparator,
-            }) => {
-                iter.advance();
-                if iter.get().is_some() {
-                    self.tree
-                        .push(StreamingTournamentEntry { iter, comparator });
-                }
+        if let Some(mut iter) = self.tree.pop(iter_order(&self.comparator)) {
+            iter.advance();
+            if iter.get().is_some() {
+                self.tree.push(iter, iter_order(&self.comparator));
             }
         }
     }
 
     fn get(&self) -> Option<&<Self as StreamingIterator>::Item> {
-        self.tree.peek().and_then(|i| i.iter.get())
+        self.tree.peek().and_then(|i| i.get())
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
         self.tree.iter().fold(
             (self.tree.len(), Some(self.tree.len())),
             |(lower, upper), i| {
-                let (l, u) = i.iter.size_hint();
+                let (l, u) = i.size_hint();
                 (lower + l, upper.zip(u).map(|(u1, u2)| u1 + u2))
             },
         )
     }
 }
 
-/// An entry into the inner binary tree that implements ['Ord`]
-/// over the inner `[StreamingIterator]` by comparing the current
-/// element of each iterator. This is implemented that way because
-/// the data is acutally owned by the iterator, and it is impossible
-/// to have any external references to it, while still allowing mutable
-/// access.
-#[derive(Clone, Debug)]
-struct StreamingTournamentEntry<I, C>
-where
-    I: StreamingIterator,
-{
-    iter: I,
-    comparator: C,
-}
-
-impl<I, C> Ord for StreamingTournamentEntry<I, C>
-where
-    I: StreamingIterator,
-    C: Comparator<I::Item>,
-{
-    fn cmp(&self, other: &Self) -> Ordering {
-        self.comparator
-            .cmp(
-                self.iter.get().as_ref().unwrap(),
-                other.iter.get().as_ref().unwrap(),
-            )
-            .reverse()
-    }
-}
-
-impl<I, C> PartialOrd for StreamingTournamentEntry<I, C>
-where
-    I: StreamingIterator,
-    C: Comparator<I::Item>,
-{
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(other))
-    }
-}
-
-impl<I, C> PartialEq for StreamingTournamentEntry<I, C>
+/// Mutable access to the current merged element, for sources that allow it, for
+/// example to annotate it in place before passing it on.
+///
+/// The current element is taken out of the merge by the next call to `advance`
+/// before anything is compared, so modifying it never breaks the order of the
+/// merge, even if it changes the order of the element itself.
+///
+/// # Examples
+///
+/// ```
+/// use streaming_iterator::{StreamingIterator, StreamingIteratorMut};
+/// use tournament_kway::StreamingTournament;
+///
+/// let mut t = StreamingTournament::from_iters_min([vec![1, 3], vec![2]].map(streaming_iterator::convert));
+/// let mut out = Vec::new();
+/// while let Some(i) = t.next_mut() {
+///     *i *= 10;
+///     out.push(*i);
+/// }
+/// assert_eq!(out, [10, 20, 30]);
+/// ```
+impl<T, F> StreamingIteratorMut for StreamingTournament<T, F>
 where
-    I: StreamingIterator,
-    C: Comparator<I::Item>,
+    T: StreamingIteratorMut,
+    F: Comparator<T::Item>,
 {
-    fn eq(&self, other: &Self) -> bool {
-        self.cmp(other).is_eq()
+    fn get_mut(&mut self) -> Option<&mut Self::Item> {
+        self.tree.peek_mut().and_then(|i| i.get_mut())
     }
 }
 
-impl<I, C> Eq for StreamingTournamentEntry<I, C>
+/// The order of the iterators in the tree, by comparing the current element of
+/// each iterator. This is implemented that way because the data is acutally owned
+/// by the iterator, and it is impossible to have any external references to it,
+/// while still allowing mutable access.
+fn iter_order<I, C>(comparator: &C) -> impl Fn(&I, &I) -> Ordering + '_
 where
     I: StreamingIterator,
     C: Comparator<I::Item>,
 {
+    move |a, b| comparator.cmp(a.get().unwrap(), b.get().unwrap())
 }
 
 #[cfg(test)]
 mod tests {
     use rand::distributions::{Alphanumeric, DistString};
-    use streaming_iterator::StreamingIterator;
+    use streaming_iterator::{StreamingIterator, StreamingIteratorMut};
 
     use crate::StreamingTournament;
 
@@ -234,4 +315,48 @@ mod tests {
 
         assert_eq!(tournament_result, sort_result);
     }
+
+    #[test]
+    fn test_ascending_descending() {
+        let ascending = StreamingTournament::from_iters_ascending(
+            [vec![1, 4], vec![2, 3]].map(streaming_iterator::convert),
+        );
+        assert_eq!(ascending.cloned().collect::<Vec<_>>(), [1, 2, 3, 4]);
+
+        let descending = StreamingTournament::from_iters_descending(
+            [vec![4, 1], vec![3, 2]].map(streaming_iterator::convert),
+        );
+        assert_eq!(descending.cloned().collect::<Vec<_>>(), [4, 3, 2, 1]);
+    }
+
+    #[test]
+    fn test_get_mut() {
+        let mut t = StreamingTournament::from_iters_min(
+            [vec![1, 4], vec![2, 3]].map(streaming_iterator::convert),
+        );
+        let mut out = Vec::new();
+        while let Some(i) = t.next_mut() {
+            // Moving the current element past the others does not affect the merge
+            *i += 100;
+            out.push(*i);
+        }
+        assert_eq!(out, [101, 102, 103, 104]);
+    }
+
+    #[test]
+    fn test_heap_len() {
+        let mut t = StreamingTournament::from_iters_min(
+            [vec![1, 3], vec![], vec![2]].map(streaming_iterator::convert),
+        );
+        assert_eq!(t.heap_len(), 2);
+        #[cfg(not(feature = "smallvec"))]
+        assert_eq!(t.heap_capacity(), 3);
+        #[cfg(feature = "smallvec")]
+        assert_eq!(t.heap_capacity(), crate::storage::INLINE);
+
+        assert_eq!(t.advance_by(3), Ok(()));
+        assert_eq!(t.heap_len(), 1);
+        t.advance();
+        assert_eq!(t.heap_len(), 0);
+    }
 }
@@ -0,0 +1,93 @@
+use std::{cmp::Ordering, fmt, sync::Arc};
+
+/// A caller's order of two sources, given their indices among all the sources added.
+pub(crate) type TieBreakFn = dyn Fn(usize, usize) -> Ordering + Send + Sync;
+
+/// How a tournament decides between sources whose current elements draw.
+#[derive(Clone, Default)]
+pub(crate) enum TieBreak {
+    /// Either source may win, whichever is cheaper for the heap
+    #[default]
+    Any,
+    /// The source that was added first wins
+    Index,
+    /// The source with the higher weight wins, and then the one that was added
+    /// first. Holds the weight of every slot.
+    Weight(Vec<u32>),
+    /// The order of a caller's function of the two sources. Holds the index of the
+    /// source of every slot among all the sources added, which is what the
+    /// function is given.
+    Custom(Arc<TieBreakFn>, Vec<usize>),
+}
+
+impl fmt::Debug for TieBreak {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TieBreak::Any => f.write_str("Any"),
+            TieBreak::Index => f.write_str("Index"),
+            TieBreak::Weight(weights) => f.debug_tuple("Weight").field(weights).finish(),
+            TieBreak::Custom(_, ids) => f.debug_tuple("Custom").field(ids).finish(),
+        }
+    }
+}
+
+impl TieBreak {
+    /// The order of two source slots whose elements draw.
+    #[inline(always)]
+    pub(crate) fn cmp(&self, a: usize, b: usize) -> Ordering {
+        match self {
+            TieBreak::Any => Ordering::Equal,
+            TieBreak::Index => a.cmp(&b),
+            TieBreak::Weight(weights) => weights[b].cmp(&weights[a]).then(a.cmp(&b)),
+            TieBreak::Custom(tie_break, ids) => tie_break(ids[a], ids[b]),
+        }
+    }
+
+    /// Record a new slot for the source `id`, with its weight, which is only kept
+    /// when breaking ties by weight.
+    pub(crate) fn push_slot(&mut self, id: usize, weight: u32) {
+        match self {
+            TieBreak::Weight(weights) => weights.push(weight),
+            TieBreak::Custom(_, ids) => ids.push(id),
+            _ => {}
+        }
+    }
+
+    /// Record the slots of another tournament that are added after the existing
+    /// ones, given the ids of their sources in this one. Slots from a tournament
+    /// that did not break ties by weight weigh 0.
+    pub(crate) fn extend_slots(&mut self, other: &TieBreak, new_ids: &[usize]) {
+        match self {
+            TieBreak::Weight(weights) => match other {
+                TieBreak::Weight(other) => weights.extend_from_slice(other),
+                _ => weights.resize(weights.len() + new_ids.len(), 0),
+            },
+            TieBreak::Custom(_, ids) => ids.extend_from_slice(new_ids),
+            _ => {}
+        }
+    }
+
+    /// Renumber the slots, as with `DoubleEnded::retain`.
+    pub(crate) fn retain_slots(&mut self, remap: &[Option<usize>]) {
+        match self {
+            TieBreak::Weight(weights) => {
+                let mut remap = remap.iter();
+                weights.retain(|_| remap.next().is_some_and(Option::is_some));
+            }
+            TieBreak::Custom(_, ids) => {
+                let mut remap = remap.iter();
+                ids.retain(|_| remap.next().is_some_and(Option::is_some));
+            }
+            _ => {}
+        }
+    }
+
+    /// Forget the weights and ids of all the slots.
+    pub(crate) fn clear_slots(&mut self) {
+        match self {
+            TieBreak::Weight(weights) => weights.clear(),
+            TieBreak::Custom(_, ids) => ids.clear(),
+            _ => {}
+        }
+    }
+}